@@ -0,0 +1,189 @@
+//! Repeated runs of solutions with statistics of parsing and every part.
+
+use crate::examples::EXAMPLES_DIR;
+use crate::input::InputSource;
+use crate::solution::{DynSolution, Part};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` for no samples.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let min = *samples.iter().min()?;
+        let max = *samples.iter().max()?;
+        let mean = samples.iter().sum::<Duration>() / samples.len().try_into().ok()?;
+        Some(Self { mean, min, max })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}..{})",
+            FormattedDuration(self.mean),
+            FormattedDuration(self.min),
+            FormattedDuration(self.max)
+        )
+    }
+}
+
+/// Duration with three significant digits in the largest suitable unit.
+pub struct FormattedDuration(pub Duration);
+
+impl Display for FormattedDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let (value, unit) = if nanos < 1e3 {
+            (nanos, "ns")
+        } else if nanos < 1e6 {
+            (nanos / 1e3, "µs")
+        } else if nanos < 1e9 {
+            (nanos / 1e6, "ms")
+        } else {
+            (nanos / 1e9, "s")
+        };
+        let precision = match value {
+            v if v < 10.0 => 2,
+            v if v < 100.0 => 1,
+            _ => 0,
+        };
+        write!(f, "{:.*}{}", precision, value, unit)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+/// Runs the solution `runs` times and collects statistics of parsing and the given parts.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Bench, String> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_samples = vec![Vec::with_capacity(runs); parts.len()];
+    for _ in 0..runs {
+        let timed = solution.run_timed(input, parts)?;
+        parse.push(timed.parse);
+        for (samples, (_, elapsed)) in part_samples.iter_mut().zip(timed.answers) {
+            samples.push(elapsed);
+        }
+    }
+
+    let no_runs = || "Number of runs must be positive".to_string();
+    Ok(Bench {
+        day: solution.day(),
+        runs,
+        parse: Stats::new(&parse).ok_or_else(no_runs)?,
+        parts: parts
+            .iter()
+            .zip(part_samples)
+            .map(|(part, samples)| Ok((*part, Stats::new(&samples).ok_or_else(no_runs)?)))
+            .collect::<Result<_, String>>()?,
+    })
+}
+
+/// Input for benchmarks of the day crate at `manifest_dir`: the real input if there is one,
+/// otherwise the first of its examples.
+pub fn bench_input(day: u8, manifest_dir: &str) -> String {
+    if let Ok(input) = InputSource::Day(day).read() {
+        return input;
+    }
+
+    let mut examples = fs::read_dir(Path::new(manifest_dir).join(EXAMPLES_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path().join("input.txt"))
+                .filter(|path| path.is_file())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    examples.sort();
+    let example = examples
+        .first()
+        .unwrap_or_else(|| panic!("No input or examples for day {} to benchmark", day));
+    InputSource::Path(example.clone())
+        .read()
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{bench, FormattedDuration, Stats};
+    use crate::solution::{Part, Solution};
+    use std::time::Duration;
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = usize;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, String> {
+            Ok(input.len())
+        }
+
+        fn part_one(&self, input: &Self::Input) -> Self::PartOne {
+            *input
+        }
+
+        fn part_two(&self, input: &Self::Input) -> Self::PartTwo {
+            input * 2
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let samples = [1, 5, 3].map(Duration::from_millis);
+        assert_eq!(
+            Stats::new(&samples),
+            Some(Stats {
+                mean: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+                max: Duration::from_millis(5),
+            })
+        );
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn format_duration() {
+        let format = |duration| FormattedDuration(duration).to_string();
+        assert_eq!(format(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format(Duration::from_nanos(1_234)), "1.23µs");
+        assert_eq!(format(Duration::from_micros(45_600)), "45.6ms");
+        assert_eq!(format(Duration::from_secs(2)), "2.00s");
+    }
+
+    #[test]
+    fn bench_parts() {
+        let result = bench(&Count, "abc", &[Part::Two], 3).unwrap();
+        assert_eq!(result.runs, 3);
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].0, Part::Two);
+        assert!(result.parse.min <= result.parse.mean && result.parse.mean <= result.parse.max);
+        assert!(bench(&Count, "abc", &[Part::One], 0).is_err());
+    }
+}
@@ -0,0 +1,296 @@
+//! Regression checks of solutions against recorded answers.
+//!
+//! Every example lives in its own directory `dayN/examples/<name>/` with `input.txt` and
+//! the expected answers in `part1.txt` and `part2.txt` (any of them can be omitted or left empty
+//! until the answer is known).
+//! The real input is checked the same way when `dayN/input.txt` has `part1.txt` or `part2.txt`
+//! next to it.
+
+use crate::input::InputSource;
+use crate::solution::{Answer, DynSolution, Part};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const EXAMPLES_DIR: &str = "examples";
+
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: Vec<(Part, Answer)>,
+}
+
+impl Fixture {
+    /// Reads the fixture from a directory, `None` if there is no `input.txt` or no answers.
+    pub fn from_dir(name: &str, dir: &Path) -> Option<Self> {
+        let input = dir.join("input.txt");
+        if !input.is_file() {
+            return None;
+        }
+
+        let expected = Part::ALL
+            .iter()
+            .filter_map(|part| {
+                fs::read_to_string(dir.join(format!("part{}.txt", part)))
+                    .ok()
+                    .filter(|answer| !answer.trim().is_empty())
+                    .map(|answer| (*part, Answer::from(answer.trim_end())))
+            })
+            .collect::<Vec<_>>();
+        if expected.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            input,
+            expected,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub fixture: String,
+    pub part: Part,
+    pub expected: Answer,
+    pub actual: Result<Answer, String>,
+}
+
+impl CheckResult {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if actual.as_str().trim_end() == self.expected.as_str())
+    }
+}
+
+impl Display for CheckResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.actual {
+            _ if self.passed() => write!(f, "{} part {}: ok", self.fixture, self.part),
+            Ok(actual) => write!(
+                f,
+                "{} part {}: expected '{}', but was '{}'",
+                self.fixture, self.part, self.expected, actual
+            ),
+            Err(err) => write!(f, "{} part {}: failed: {}", self.fixture, self.part, err),
+        }
+    }
+}
+
+/// Directories of `day_dir/examples` ordered by name.
+fn example_dirs(day_dir: &Path) -> Vec<PathBuf> {
+    let mut example_dirs = fs::read_dir(day_dir.join(EXAMPLES_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    example_dirs.sort();
+    example_dirs
+}
+
+/// Examples from `day_dir/examples` ordered by name and the real input `day_dir/input.txt`
+/// if it has answers. Both are resolved relative to `day_dir`, not the current directory.
+pub fn fixtures(day_dir: &Path) -> Vec<Fixture> {
+    let examples = example_dirs(day_dir).into_iter().filter_map(|dir| {
+        let name = dir.file_name()?.to_string_lossy().to_string();
+        Fixture::from_dir(&name, &dir)
+    });
+    let real_input = Fixture::from_dir("input", day_dir);
+
+    examples.chain(real_input).collect()
+}
+
+/// Names of examples with `input.txt` whose answers are not filled in yet, like the ones
+/// written by `aoc new`.
+pub fn pending_examples(day_dir: &Path) -> Vec<String> {
+    example_dirs(day_dir)
+        .into_iter()
+        .filter(|dir| dir.join("input.txt").is_file())
+        .filter(|dir| Fixture::from_dir("", dir).is_none())
+        .filter_map(|dir| Some(dir.file_name()?.to_string_lossy().to_string()))
+        .collect()
+}
+
+pub fn check_fixture(solution: &dyn DynSolution, fixture: &Fixture) -> Vec<CheckResult> {
+    let parts = fixture
+        .expected
+        .iter()
+        .map(|(part, _)| *part)
+        .collect::<Vec<_>>();
+    let answers = InputSource::Path(fixture.input.clone())
+        .read()
+        .map_err(|err| err.to_string())
+        .and_then(|input| solution.run(&input, &parts));
+
+    fixture
+        .expected
+        .iter()
+        .enumerate()
+        .map(|(i, (part, expected))| CheckResult {
+            fixture: fixture.name.clone(),
+            part: *part,
+            expected: expected.clone(),
+            actual: answers
+                .as_ref()
+                .map(|answers| answers[i].clone())
+                .map_err(|err| err.clone()),
+        })
+        .collect()
+}
+
+pub fn check(solution: &dyn DynSolution, day_dir: &Path) -> Vec<CheckResult> {
+    fixtures(day_dir)
+        .iter()
+        .flat_map(|fixture| check_fixture(solution, fixture))
+        .collect()
+}
+
+/// Checks all fixtures of the day crate at `manifest_dir` and panics listing the failed ones.
+/// Passes while all examples are still waiting for their answers, so a new day can be tested.
+/// Meant to be called from tests with `env!("CARGO_MANIFEST_DIR")`.
+pub fn assert_fixtures(solution: &dyn DynSolution, manifest_dir: &str) {
+    let results = check(solution, Path::new(manifest_dir));
+    if results.is_empty() {
+        let pending = pending_examples(Path::new(manifest_dir));
+        assert!(
+            !pending.is_empty(),
+            "No examples found for day {} in {}",
+            solution.day(),
+            manifest_dir
+        );
+        eprintln!(
+            "Answers of day {} examples are not filled in yet: {}",
+            solution.day(),
+            pending.join(", ")
+        );
+        return;
+    }
+
+    let failed = results
+        .iter()
+        .filter(|result| !result.passed())
+        .map(|result| result.to_string())
+        .collect::<Vec<_>>();
+    assert!(failed.is_empty(), "{}", failed.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{assert_fixtures, check, pending_examples, Fixture};
+    use crate::solution::{Answer, Part, Solution};
+    use std::env::temp_dir;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<String>;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, String> {
+            Ok(input.lines().map(|line| line.to_string()).collect())
+        }
+
+        fn part_one(&self, input: &Self::Input) -> Self::PartOne {
+            input.len()
+        }
+
+        fn part_two(&self, input: &Self::Input) -> Self::PartTwo {
+            input.join("\n")
+        }
+    }
+
+    fn day_dir(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("advent_util_examples_{}_{}", name, process::id()));
+        let example = dir.join("examples").join("first");
+        fs::create_dir_all(&example).unwrap();
+        fs::write(example.join("input.txt"), "a\nb\n").unwrap();
+        fs::write(example.join("part1.txt"), "2\n").unwrap();
+        fs::write(example.join("part2.txt"), "a\nb\n").unwrap();
+
+        let only_second = dir.join("examples").join("second");
+        fs::create_dir_all(&only_second).unwrap();
+        fs::write(only_second.join("input.txt"), "c").unwrap();
+        fs::write(only_second.join("part1.txt"), "\n").unwrap();
+        fs::write(only_second.join("part2.txt"), "d").unwrap();
+        dir
+    }
+
+    #[test]
+    fn read_fixture() {
+        let dir = day_dir("read");
+        let fixture = Fixture::from_dir("second", &dir.join("examples").join("second")).unwrap();
+        assert_eq!(fixture.expected, vec![(Part::Two, Answer::from("d"))]);
+        assert!(Fixture::from_dir("none", &dir).is_none());
+    }
+
+    #[test]
+    fn examples_without_answers() {
+        let dir = day_dir("pending");
+        assert!(pending_examples(&dir).is_empty());
+
+        let new_day = temp_dir().join(format!("advent_util_examples_new_{}", process::id()));
+        let example = new_day.join("examples").join("example");
+        fs::create_dir_all(&example).unwrap();
+        fs::write(example.join("input.txt"), "a\n").unwrap();
+        fs::write(example.join("part1.txt"), "").unwrap();
+        fs::write(example.join("part2.txt"), "").unwrap();
+        assert_eq!(pending_examples(&new_day), vec!["example".to_string()]);
+        assert_fixtures(&Lines, new_day.to_str().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "No examples found")]
+    fn no_examples() {
+        let empty = temp_dir().join(format!("advent_util_examples_empty_{}", process::id()));
+        fs::create_dir_all(&empty).unwrap();
+        assert_fixtures(&Lines, empty.to_str().unwrap());
+    }
+
+    #[test]
+    fn real_input_next_to_examples() {
+        let dir = day_dir("real");
+        fs::write(dir.join("input.txt"), "x\ny\nz\n").unwrap();
+        fs::write(dir.join("part1.txt"), "3\n").unwrap();
+        let results = check(&Lines, &dir);
+        let real = results
+            .iter()
+            .filter(|result| result.fixture == "input")
+            .collect::<Vec<_>>();
+        assert_eq!(real.len(), 1);
+        assert!(real[0].passed(), "{}", real[0]);
+    }
+
+    #[test]
+    fn check_examples() {
+        let results = check(&Lines, &day_dir("check"));
+        let summary = results
+            .iter()
+            .map(|result| (result.fixture.as_str(), result.part, result.passed()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("first", Part::One, true),
+                ("first", Part::Two, true),
+                ("second", Part::Two, false),
+            ]
+        );
+        assert_eq!(
+            results[2].to_string(),
+            "second part 2: expected 'd', but was 'c'"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Rectangular grid addressed by `(row, column)` positions, as in character maps of puzzles.
+
+use crate::parse::{self, ParseError};
+use std::ops::{Index, IndexMut};
+
+pub type Position = (usize, usize);
+
+/// Steps to the top, right, bottom and left neighbours as `(row, column)` deltas.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// Steps to all eight neighbours clockwise starting from the top one.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(height: usize, width: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// `None` if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, one line per row.
+    pub fn parse(
+        input: &str,
+        parse_cell: impl Fn(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        parse::grid(input, parse_cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Cell at signed coordinates, `None` for negative ones or outside of the grid.
+    pub fn get_signed(&self, row: isize, column: isize) -> Option<&T> {
+        self.get((row.try_into().ok()?, column.try_into().ok()?))
+    }
+
+    /// Position after the step, if it is inside of the grid.
+    pub fn step(
+        &self,
+        (row, column): Position,
+        (d_row, d_column): (isize, isize),
+    ) -> Option<Position> {
+        let position = (
+            row.checked_add_signed(d_row)?,
+            column.checked_add_signed(d_column)?,
+        );
+        Some(position).filter(|position| self.contains(*position))
+    }
+
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |step| self.step(position, *step))
+    }
+
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |step| self.step(position, *step))
+    }
+
+    /// Positions from the one next to `from` in the direction up to the border of the grid.
+    pub fn ray(
+        &self,
+        from: Position,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = Position> + '_ {
+        let mut current = Some(from);
+        std::iter::from_fn(move || {
+            current = self.step(current?, direction);
+            current
+        })
+    }
+
+    /// Panics if the row is outside of the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(
+            row < self.height,
+            "Row {} is outside of the grid {}x{}",
+            row,
+            self.height,
+            self.width
+        );
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max` keeps `chunks` from panicking on grids without columns
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Panics if the column is outside of the grid.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column < self.width,
+            "Column {} is outside of the grid {}x{}",
+            column,
+            self.height,
+            self.width
+        );
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Cells with their positions row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Text with a character per cell and a line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        let (row, column) = position;
+        self.contains(position).then(|| row * self.width + column)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(value: T, height: usize, width: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "Position {:?} is outside of the grid {}x{}",
+                position, self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!(
+                "Position {:?} is outside of the grid {}x{}",
+                position, height, width
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use crate::parse::ParseError;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new("Expected digit", &c.to_string()))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn access() {
+        let mut grid = digits();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get_signed(0, 2), Some(&3));
+        assert_eq!(grid.get_signed(-1, 2), None);
+
+        grid[(0, 0)] = 7;
+        assert_eq!(grid.row(0), &[7, 2, 3]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.get((usize::MAX, usize::MAX)), None);
+        assert_eq!(grid.get((1, usize::MAX)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 3 is outside of the grid 2x3")]
+    fn column_outside() {
+        digits().column(3).for_each(drop);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 2 is outside of the grid 2x3")]
+    fn row_outside() {
+        digits().row(2);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits();
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours_4((1, 1)).count(), 3);
+        assert_eq!(grid.neighbours_8((0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rays() {
+        let grid = digits();
+        assert_eq!(
+            grid.ray((1, 0), (0, 1)).collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2)]
+        );
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+        assert_eq!(
+            grid.ray((1, 2), (-1, -1))
+                .map(|p| grid[p])
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn build_and_render() {
+        let grid = Grid::from_fn(2, 2, |(row, column)| row * 2 + column);
+        assert_eq!(
+            Grid::from_rows(vec![vec![0, 1], vec![2, 3]]),
+            Some(grid.clone())
+        );
+        assert_eq!(Grid::from_rows(vec![vec![0, 1], vec![2]]), None);
+        assert_eq!(
+            grid.map(|cell| if cell % 3 == 0 { '#' } else { '.' })
+                .render(|cell| *cell),
+            "#.\n.#"
+        );
+        assert_eq!(Grid::filled('.', 1, 3).render(|cell| *cell), "...");
+    }
+}
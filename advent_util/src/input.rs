@@ -0,0 +1,258 @@
+use std::convert::Infallible;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, File};
+use std::io;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Directory with `dayN/input.txt` files, which overrides the search from the current directory.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Workspace root at the moment of compilation, used when binaries are started outside of it.
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayN/input.txt` from [INPUT_DIR_VAR] if it is set, otherwise from the current directory,
+    /// one of its parents or the workspace.
+    Day(u8),
+    Path(PathBuf),
+    Stdin,
+    Embedded(&'static str),
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        day: u8,
+        searched: Vec<PathBuf>,
+    },
+    Io {
+        source_name: String,
+        error: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { day, searched } => {
+                write!(
+                    f,
+                    "Cannot find input for day {} in {}. Download it with 'aoc fetch {}'",
+                    day,
+                    searched
+                        .iter()
+                        .map(|path| format!("'{}'", path.display()))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    day
+                )
+            }
+            InputError::Io { source_name, error } => {
+                write!(f, "Cannot read from {}: {}", source_name, error)
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Day(day) => read_file(&day_input_path(*day)?),
+            InputSource::Path(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| InputError::Io {
+                        source_name: "stdin".to_string(),
+                        error,
+                    })?;
+                Ok(input)
+            }
+            InputSource::Embedded(input) => Ok(input.to_string()),
+        }
+    }
+
+    /// Like [InputSource::read], but line by line, so big inputs do not have to fit in memory.
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, InputError> {
+        let open = |path: &Path| {
+            File::open(path).map_err(|error| InputError::Io {
+                source_name: format!("'{}'", path.display()),
+                error,
+            })
+        };
+        Ok(match self {
+            InputSource::Day(day) => Box::new(BufReader::new(open(&day_input_path(*day)?)?)),
+            InputSource::Path(path) => Box::new(BufReader::new(open(path)?)),
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::Embedded(input) => Box::new(input.as_bytes()),
+        })
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_arg(s))
+    }
+}
+
+/// The closest parent of the current directory with `[workspace]` in its `Cargo.toml`,
+/// [WORKSPACE_DIR] outside of any workspace.
+pub fn workspace_dir() -> PathBuf {
+    env::current_dir()
+        .ok()
+        .and_then(|dir| {
+            dir.ancestors()
+                .find(|dir| is_workspace(dir))
+                .map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| PathBuf::from(WORKSPACE_DIR))
+}
+
+fn is_workspace(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .map(|manifest| manifest.contains("[workspace]"))
+        .unwrap_or(false)
+}
+
+/// Directory of the `dayN` crate, searched in the current directory, its parents and the workspace.
+pub fn find_day_dir(day: u8) -> Option<PathBuf> {
+    let current_dir = env::current_dir().ok()?;
+    search_paths(Path::new(&format!("day{}", day)), &current_dir)
+        .into_iter()
+        .find(|path| path.is_dir())
+}
+
+fn search_paths(relative_path: &Path, current_dir: &Path) -> Vec<PathBuf> {
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join(relative_path))
+        .chain([Path::new(WORKSPACE_DIR).join(relative_path)])
+        .collect()
+}
+
+/// Path of `dayN/input.txt`, see [InputSource::Day] for the places where it is searched.
+pub fn day_input_path(day: u8) -> Result<PathBuf, InputError> {
+    let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    let current_dir = env::current_dir().map_err(|error| InputError::Io {
+        source_name: "current directory".to_string(),
+        error,
+    })?;
+    find_day_input(day, input_dir.as_deref(), &current_dir)
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    let io_error = |error| InputError::Io {
+        source_name: format!("'{}'", path.display()),
+        error,
+    };
+    let mut input_file = File::open(path).map_err(io_error)?;
+    let mut input = String::new();
+    input_file.read_to_string(&mut input).map_err(io_error)?;
+    Ok(input)
+}
+
+fn find_day_input(
+    day: u8,
+    input_dir: Option<&Path>,
+    current_dir: &Path,
+) -> Result<PathBuf, InputError> {
+    let relative_path = PathBuf::from(format!("day{}", day)).join("input.txt");
+    let searched = if let Some(input_dir) = input_dir {
+        vec![input_dir.join(&relative_path)]
+    } else {
+        search_paths(&relative_path, current_dir)
+    };
+
+    searched
+        .iter()
+        .find(|path| path.is_file())
+        .cloned()
+        .ok_or(InputError::NotFound { day, searched })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{find_day_input, InputError, InputSource};
+    use std::env::temp_dir;
+    use std::fs;
+    use std::io::BufRead;
+    use std::path::PathBuf;
+    use std::process;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("advent_util_input_{}_{}", name, process::id()));
+        fs::create_dir_all(dir.join("day3").join("nested")).unwrap();
+        fs::write(dir.join("day3").join("input.txt"), "day 3 input").unwrap();
+        dir
+    }
+
+    #[test]
+    fn read_embedded() {
+        assert_eq!(InputSource::Embedded("abc").read().unwrap(), "abc");
+        let lines = InputSource::Embedded("a\nb\n").reader().unwrap().lines();
+        assert_eq!(lines.map(Result::unwrap).collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn parse_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "day1/input.txt".parse(),
+            Ok(InputSource::Path(PathBuf::from("day1/input.txt")))
+        );
+    }
+
+    #[test]
+    fn find_in_parent_directory() {
+        let dir = test_dir("parent");
+        let path = find_day_input(3, None, &dir.join("day3").join("nested")).unwrap();
+        assert_eq!(path, dir.join("day3").join("input.txt"));
+        assert_eq!(InputSource::Path(path).read().unwrap(), "day 3 input");
+    }
+
+    #[test]
+    fn find_in_input_dir() {
+        let dir = test_dir("input_dir");
+        let path = find_day_input(3, Some(&dir), &temp_dir()).unwrap();
+        assert_eq!(path, dir.join("day3").join("input.txt"));
+
+        let error = find_day_input(4, Some(&dir), &dir).unwrap_err();
+        assert!(matches!(
+            error,
+            InputError::NotFound { day: 4, ref searched } if searched == &vec![dir.join("day4").join("input.txt")]
+        ));
+    }
+
+    #[test]
+    fn read_missing_file() {
+        let error = InputSource::Path(PathBuf::from("no/such/input.txt"))
+            .read()
+            .unwrap_err();
+        assert!(matches!(error, InputError::Io { .. }));
+    }
+}
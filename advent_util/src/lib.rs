@@ -1,14 +1,22 @@
-use std::fs::File;
-use std::io::Read;
+use crate::input::{InputError, InputSource};
 
-pub mod slices;
+pub mod bench;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod point;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "viz")]
+pub mod viz;
+pub mod windows;
 
-pub fn read_input(day: u8) -> Result<String, String> {
-    let mut input_file = File::open(format!("day{}/input.txt", day))
-        .map_err(|_| format!("Download file to day{} with 'download.http'", day))?;
-    let mut input = String::new();
-    input_file
-        .read_to_string(&mut input)
-        .map_err(|e| format!("Cannot read from file: {:?}", e))?;
-    Ok(input)
+/// Reads `dayN/input.txt`, see [InputSource::Day] for the places where it is searched.
+pub fn read_input(day: u8) -> Result<String, InputError> {
+    InputSource::Day(day).read()
 }
@@ -0,0 +1,87 @@
+use crate::solution::{print_answer, Part, Timed};
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum OutputFormat {
+    /// `Day N part M: answer` lines for humans.
+    #[default]
+    Text,
+    /// One [AnswerRecord] per line for scripts.
+    Json,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            other => Err(format!("Unknown format '{}', expected text or json", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnswerRecord<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    /// Seconds spent on the part, without parsing of the input.
+    pub elapsed: f64,
+}
+
+/// Prints answers of the given parts in the same order as they were solved.
+pub fn print_answers(day: u8, parts: &[Part], timed: &Timed, format: OutputFormat) {
+    for (part, (answer, elapsed)) in parts.iter().zip(&timed.answers) {
+        match format {
+            OutputFormat::Text => print_answer(day, *part, answer),
+            OutputFormat::Json => {
+                let record = AnswerRecord {
+                    day,
+                    part: part.number(),
+                    answer: answer.as_str(),
+                    elapsed: elapsed.as_secs_f64(),
+                };
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::{AnswerRecord, OutputFormat};
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn serialize_record() {
+        let record = AnswerRecord {
+            day: 10,
+            part: 2,
+            answer: "##..\n#..#",
+            elapsed: 0.5,
+        };
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r###"{"day":10,"part":2,"answer":"##..\n#..#","elapsed":0.5}"###
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! Helpers for parsing puzzle inputs with errors pointing to the offending text.
+//!
+//! Parsers of single items (e.g. `FromStr` implementations) report only what is wrong and
+//! the text they could not parse. Combinators like [lines] and [blocks] know where the item
+//! starts in the whole input, so they add the line and the column to the error.
+
+use crate::grid::Grid;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// 1-based position in the input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    /// Unknown until the error reaches a combinator which knows where the text is.
+    pub position: Option<Position>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>, text: &str) -> Self {
+        Self {
+            message: message.into(),
+            text: text.to_string(),
+            position: None,
+        }
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.position = Some(Position { line, column });
+        self
+    }
+
+    /// Moves the error from a part of the text to the whole text, where the part starts at
+    /// `line` and `column`. The known position is relative to the part, otherwise the error
+    /// points to the start of the part.
+    pub fn offset(mut self, line: usize, column: usize) -> Self {
+        self.position = Some(match self.position {
+            Some(position) => Position {
+                line: position.line + line - 1,
+                column: if position.line == 1 {
+                    position.column + column - 1
+                } else {
+                    position.column
+                },
+            },
+            None => Position { line, column },
+        });
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(position) = self.position {
+            write!(f, "{}: ", position)?;
+        }
+        write!(f, "{}: '{}'", self.message, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(error: ParseError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Parses every line of the input.
+pub fn lines<T>(
+    input: &str,
+    parse_line: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|err| err.offset(i + 1, 1)))
+        .collect()
+}
+
+/// Parses blocks of lines separated by blank lines. Several blank lines in a row separate
+/// blocks like one, and lines end with `\n` or `\r\n`.
+pub fn blocks<T>(
+    input: &str,
+    parse_block: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut parsed = Vec::new();
+    let mut block = Vec::new();
+    let mut first_line = 1;
+    for (i, line) in input.lines().chain([""]).enumerate() {
+        if !line.trim().is_empty() {
+            if block.is_empty() {
+                first_line = i + 1;
+            }
+            block.push(line);
+        } else if !block.is_empty() {
+            let text = block.join("\n");
+            parsed.push(parse_block(&text).map_err(|err| err.offset(first_line, 1))?);
+            block.clear();
+        }
+    }
+    Ok(parsed)
+}
+
+pub fn number<T>(text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.trim()
+        .parse()
+        .map_err(|err| ParseError::new(format!("Cannot parse number: {}", err), text))
+}
+
+/// 1-based column of the byte offset in a single line of text.
+fn column(text: &str, offset: usize) -> usize {
+    text[..offset].chars().count() + 1
+}
+
+/// Items separated by the separator, errors point into the item.
+pub fn separated<T>(
+    text: &str,
+    separator: &str,
+    parse_item: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut offset = 0;
+    text.split(separator)
+        .map(|item| {
+            let parsed = parse_item(item).map_err(|err| err.offset(1, column(text, offset)));
+            offset += item.len() + separator.len();
+            parsed
+        })
+        .collect()
+}
+
+/// Numbers separated by the separator, e.g. `79, 98` with `,`.
+pub fn numbers<T>(text: &str, separator: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    separated(text, separator, number)
+}
+
+/// Both sides of the first separator.
+pub fn split_pair<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::new(format!("Expected '{}'", separator), text))
+}
+
+/// Parses both sides of the first separator, errors point into the side.
+pub fn pair<A, B>(
+    text: &str,
+    separator: &str,
+    parse_left: impl FnOnce(&str) -> Result<A, ParseError>,
+    parse_right: impl FnOnce(&str) -> Result<B, ParseError>,
+) -> Result<(A, B), ParseError> {
+    let (left, right) = split_pair(text, separator)?;
+    let left = parse_left(left).map_err(|err| err.offset(1, 1))?;
+    let right_start = column(text, text.len() - right.len());
+    let right = parse_right(right).map_err(|err| err.offset(1, right_start))?;
+    Ok((left, right))
+}
+
+/// The rest of the text after the expected prefix.
+pub fn after<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(format!("Expected '{}'", prefix), text))
+}
+
+/// Parses the rest of the text after the expected prefix, errors point into the rest.
+pub fn parse_after<T>(
+    text: &str,
+    prefix: &str,
+    parse_rest: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let rest = after(text, prefix)?;
+    parse_rest(rest).map_err(|err| err.offset(1, column(text, prefix.len())))
+}
+
+/// Rectangular grid of cells, one line per row.
+pub fn grid<T>(
+    input: &str,
+    parse_cell: impl Fn(char) -> Result<T, ParseError>,
+) -> Result<Grid<T>, ParseError> {
+    let mut width = None;
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            let cells = line
+                .chars()
+                .enumerate()
+                .map(|(column, cell)| parse_cell(cell).map_err(|err| err.at(row + 1, column + 1)))
+                .collect::<Result<Vec<_>, _>>()?;
+            match width {
+                Some(width) if width != cells.len() => Err(ParseError::new(
+                    format!("Expected {} cells, but was {}", width, cells.len()),
+                    line,
+                )
+                .at(row + 1, 1)),
+                _ => {
+                    width = Some(cells.len());
+                    Ok(cells)
+                }
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Grid::from_rows(rows).expect("rows have the same length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use crate::parse::{
+        after, blocks, grid, lines, number, numbers, pair, parse_after, separated, split_pair,
+        ParseError,
+    };
+
+    fn digit(c: char) -> Result<u32, ParseError> {
+        c.to_digit(10)
+            .ok_or_else(|| ParseError::new("Expected digit", &c.to_string()))
+    }
+
+    #[test]
+    fn parse_lines() {
+        assert_eq!(lines("1\n2\n", number::<u8>), Ok(vec![1, 2]));
+
+        let error = lines("1,2\n3,x4", |line| numbers::<u8>(line, ",")).unwrap_err();
+        assert_eq!(error.text, "x4");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: Cannot parse number: invalid digit found in string: 'x4'"
+        );
+    }
+
+    #[test]
+    fn parse_blocks() {
+        let input = "a: 1\nb: 2\n\na: 3\nb: y";
+        let parse_block = |block: &str| {
+            lines(block, |line| {
+                pair(line, ": ", |key| Ok(key.to_string()), number::<u8>)
+            })
+        };
+        assert!(blocks("a: 1\n\nb: 2", parse_block).is_ok());
+
+        let error = blocks(input, parse_block).unwrap_err();
+        assert_eq!(error.position.map(|p| (p.line, p.column)), Some((5, 4)));
+    }
+
+    #[test]
+    fn blocks_separated_by_blank_lines() {
+        let sizes = |input| blocks(input, |block| lines(block, number::<u8>).map(|n| n.len()));
+        assert_eq!(sizes("1\r\n2\r\n\r\n3\r\n"), Ok(vec![2, 1]));
+        assert_eq!(sizes("\n1\n\n\n \n2\n3\n\n"), Ok(vec![1, 2]));
+        assert_eq!(sizes(""), Ok(vec![]));
+
+        let error = sizes("1\r\n\r\n\r\n2\r\nx").unwrap_err();
+        assert_eq!(error.position.map(|p| (p.line, p.column)), Some((5, 1)));
+    }
+
+    #[test]
+    fn repeated_text() {
+        // the wrong text also appears earlier in the line
+        let error = lines("1 1\nx x", |line| {
+            pair(line, " ", number::<u8>, number::<u8>)
+        })
+        .unwrap_err();
+        assert_eq!(error.position.map(|p| (p.line, p.column)), Some((2, 1)));
+        let error = lines("x -> 1 -> x", |line| {
+            parse_after(line, "x -> ", |rest| separated(rest, " -> ", number::<u8>))
+        })
+        .unwrap_err();
+        assert_eq!(error.position.map(|p| (p.line, p.column)), Some((1, 11)));
+        let error = lines("1 x", |line| pair(line, " ", |_| Ok(()), number::<u8>)).unwrap_err();
+        assert_eq!(error.position.map(|p| (p.line, p.column)), Some((1, 3)));
+        let error = lines("x x", |line| pair(line, " ", |_| Ok(()), number::<u8>)).unwrap_err();
+        assert_eq!(error.position.map(|p| (p.line, p.column)), Some((1, 3)));
+    }
+
+    #[test]
+    fn parse_grid() {
+        assert_eq!(
+            grid("12\n34", digit),
+            Ok(Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap())
+        );
+
+        let error = grid("12\n3a", digit).unwrap_err();
+        assert_eq!(error.position.map(|p| (p.line, p.column)), Some((2, 2)));
+        assert!(grid("12\n3", digit).is_err());
+    }
+
+    #[test]
+    fn expected_text() {
+        assert_eq!(split_pair("1-2", "-"), Ok(("1", "2")));
+        assert_eq!(after("$ ls", "$ "), Ok("ls"));
+
+        let error = lines("ab\ncd", |line| after(line, "a").map(str::to_string)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: Expected 'a': 'cd'");
+    }
+}
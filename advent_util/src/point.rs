@@ -0,0 +1,402 @@
+//! Points on a plane and in space with directions between neighbouring points.
+//!
+//! The `y` axis goes down as rows of puzzle maps do, so [Direction::Up] decreases `y`
+//! and turning right is clockwise on the screen.
+
+use crate::grid::Position;
+use crate::parse::{numbers, ParseError};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+/// Component-wise arithmetic shared by the points.
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl $point {
+            pub const ZERO: Self = Self { $($field: 0),* };
+
+            /// Distance walking along the axes.
+            pub fn manhattan(&self, other: Self) -> usize {
+                0 $(+ self.$field.abs_diff(other.$field))*
+            }
+
+            /// Distance moving diagonally as well, i.e. the number of king moves.
+            pub fn chebyshev(&self, other: Self) -> usize {
+                0 $(.max(self.$field.abs_diff(other.$field)))*
+            }
+
+            /// Every component reduced to `-1`, `0` or `1`, a single step towards the point.
+            pub fn signum(&self) -> Self {
+                Self { $($field: self.$field.signum()),* }
+            }
+
+            pub fn component_min(&self, other: Self) -> Self {
+                Self { $($field: self.$field.min(other.$field)),* }
+            }
+
+            pub fn component_max(&self, other: Self) -> Self {
+                Self { $($field: self.$field.max(other.$field)),* }
+            }
+
+            /// Inclusive corners of the smallest box containing all the points.
+            pub fn bounds(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+                points.into_iter().fold(None, |bounds, point| {
+                    Some(bounds.map_or((point, point), |(min, max): (Self, Self)| {
+                        (min.component_min(point), max.component_max(point))
+                    }))
+                })
+            }
+
+            /// Whether the point is inside of the inclusive box.
+            pub fn within(&self, min: Self, max: Self) -> bool {
+                true $(&& (min.$field..=max.$field).contains(&self.$field))*
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl Mul<isize> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: isize) -> Self {
+                Self { $($field: self.$field * factor),* }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+impl Point2 {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Point of the `(row, column)` position of a grid.
+    pub fn from_position((row, column): Position) -> Self {
+        Self::new(column as isize, row as isize)
+    }
+
+    /// `(row, column)` position in a grid, `None` for negative coordinates.
+    pub fn to_position(&self) -> Option<Position> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    pub fn step(&self, direction: Direction) -> Self {
+        *self + direction.delta()
+    }
+
+    /// Rotation by 90 degrees clockwise around the origin.
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotation by 90 degrees counterclockwise around the origin.
+    pub fn rotate_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn neighbours_4(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        Direction::ALL_4.into_iter().map(move |d| point.step(d))
+    }
+
+    pub fn neighbours_8(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        Direction::ALL_8.into_iter().map(move |d| point.step(d))
+    }
+}
+
+impl Point3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Neighbours sharing a face, i.e. one step along an axis.
+    pub fn neighbours_6(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+        [
+            Self::new(1, 0, 0),
+            Self::new(-1, 0, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 0, 1),
+            Self::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |delta| point + delta)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Parses `x,y`.
+impl FromStr for Point2 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match numbers(s, ",")?[..] {
+            [x, y] => Ok(Self::new(x, y)),
+            _ => Err(ParseError::new("Expected 'x,y'", s)),
+        }
+    }
+}
+
+/// Parses `x,y,z`.
+impl FromStr for Point3 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match numbers(s, ",")?[..] {
+            [x, y, z] => Ok(Self::new(x, y, z)),
+            _ => Err(ParseError::new("Expected 'x,y,z'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Directions along the axes clockwise starting from [Direction::Up].
+    pub const ALL_4: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+    /// All directions clockwise starting from [Direction::Up].
+    pub const ALL_8: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub fn delta(&self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::UpRight => Point2::new(1, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::DownRight => Point2::new(1, 1),
+            Direction::Down => Point2::new(0, 1),
+            Direction::DownLeft => Point2::new(-1, 1),
+            Direction::Left => Point2::new(-1, 0),
+            Direction::UpLeft => Point2::new(-1, -1),
+        }
+    }
+
+    /// Direction of a single step, `None` for longer steps or no step at all.
+    pub fn from_delta(delta: Point2) -> Option<Self> {
+        Self::ALL_8
+            .into_iter()
+            .find(|direction| direction.delta() == delta)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !Self::ALL_4.contains(self)
+    }
+
+    /// Turn by 90 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        self.turn(2)
+    }
+
+    /// Turn by 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        self.turn(6)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn(4)
+    }
+
+    /// Arrow as in `^>v<` maps, `None` for diagonal directions.
+    pub fn arrow(&self) -> Option<char> {
+        match self {
+            Direction::Up => Some('^'),
+            Direction::Right => Some('>'),
+            Direction::Down => Some('v'),
+            Direction::Left => Some('<'),
+            _ => None,
+        }
+    }
+
+    /// Turn by `eighths` of the full circle clockwise.
+    fn turn(&self, eighths: usize) -> Self {
+        let index = Self::ALL_8
+            .iter()
+            .position(|direction| direction == self)
+            .expect("all directions are listed");
+        Self::ALL_8[(index + eighths) % 8]
+    }
+}
+
+/// Parses `U`, `D`, `L`, `R`, arrows `^`, `v`, `<`, `>` and compass points `N`, `NE`, `E`, etc.
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "^" | "N" => Ok(Direction::Up),
+            "NE" => Ok(Direction::UpRight),
+            "R" | ">" | "E" => Ok(Direction::Right),
+            "SE" => Ok(Direction::DownRight),
+            "D" | "v" | "S" => Ok(Direction::Down),
+            "SW" => Ok(Direction::DownLeft),
+            "L" | "<" | "W" => Ok(Direction::Left),
+            "NW" => Ok(Direction::UpLeft),
+            _ => Err(ParseError::new("Unknown direction", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::{Direction, Point2, Point3};
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, -4));
+        assert_eq!(-a * 2, Point2::new(-2, -4));
+        assert_eq!((b - a).signum(), Point2::new(1, -1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c.manhattan(Point3::ZERO), 6);
+        assert_eq!(c.chebyshev(Point3::ZERO), 3);
+        assert_eq!(c.neighbours_6().count(), 6);
+        assert_eq!("1,2,3".parse(), Ok(c));
+        assert_eq!("1,2".parse(), Ok(a));
+        assert!("1,2".parse::<Point3>().is_err());
+    }
+
+    #[test]
+    fn bounds() {
+        let points = [Point2::new(1, 5), Point2::new(-3, 2), Point2::new(0, 7)];
+        let (min, max) = Point2::bounds(points).unwrap();
+        assert_eq!((min, max), (Point2::new(-3, 2), Point2::new(1, 7)));
+        assert!(Point2::new(0, 4).within(min, max));
+        assert!(!Point2::new(2, 4).within(min, max));
+        assert_eq!(Point2::bounds([]), None);
+    }
+
+    #[test]
+    fn rotation() {
+        let right = Direction::Right.delta();
+        assert_eq!(right.rotate_right(), Direction::Down.delta());
+        assert_eq!(right.rotate_left(), Direction::Up.delta());
+        assert_eq!(
+            Point2::new(2, 1).rotate_right().rotate_left(),
+            Point2::new(2, 1)
+        );
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpRight.opposite(), Direction::DownLeft);
+    }
+
+    #[test]
+    fn directions() {
+        for (text, direction) in [
+            ("U", Direction::Up),
+            ("^", Direction::Up),
+            ("N", Direction::Up),
+            (">", Direction::Right),
+            ("D", Direction::Down),
+            ("SW", Direction::DownLeft),
+            ("W", Direction::Left),
+        ] {
+            assert_eq!(text.parse(), Ok(direction));
+        }
+        assert!("X".parse::<Direction>().is_err());
+
+        let point = Point2::new(3, 3);
+        assert_eq!(point.neighbours_4().count(), 4);
+        assert!(point
+            .neighbours_8()
+            .all(|neighbour| point.chebyshev(neighbour) == 1));
+        assert_eq!(
+            Direction::from_delta(Point2::new(-1, 1)),
+            Some(Direction::DownLeft)
+        );
+        assert_eq!(Direction::from_delta(Point2::new(2, 0)), None);
+        assert!(Direction::UpLeft.is_diagonal());
+        assert_eq!(Direction::Down.arrow(), Some('v'));
+        assert_eq!(Point2::from_position((2, 5)).to_position(), Some((2, 5)));
+    }
+}
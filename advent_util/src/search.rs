@@ -0,0 +1,303 @@
+//! Shortest paths in graphs given by a function returning neighbours of a node.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cost of edges and paths.
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(impl Cost for $t {
+            const ZERO: Self = 0;
+        })*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, usize, i32, i64, isize);
+
+/// Distances to all reached nodes with the previous node on a shortest path to each of them.
+#[derive(Debug, Clone)]
+pub struct Distances<N, C> {
+    reached: HashMap<N, (C, Option<N>)>,
+}
+
+impl<N: Eq + Hash + Clone, C: Cost> Distances<N, C> {
+    fn new() -> Self {
+        Self {
+            reached: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|(distance, _)| *distance)
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.reached.contains_key(node)
+    }
+
+    /// Shortest path from one of the sources to the node, both ends included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut previous = self.reached.get(node)?.1.clone();
+        while let Some(node) = previous {
+            previous = self.reached[&node].1.clone();
+            path.push(node);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Reached nodes with their distances in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached
+            .iter()
+            .map(|(node, (distance, _))| (node, *distance))
+    }
+}
+
+/// Breadth-first search, where every edge costs 1.
+pub fn bfs<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Distances<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbours)
+}
+
+/// Breadth-first search from several sources at once, so every node gets the distance
+/// to the closest of them.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Distances<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = Distances::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = distances.reached.entry(start.clone()) {
+            entry.insert((0, None));
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((current, distance)) = queue.pop_front() {
+        for neighbour in neighbours(&current) {
+            if let Entry::Vacant(entry) = distances.reached.entry(neighbour.clone()) {
+                entry.insert((distance + 1, Some(current.clone())));
+                queue.push_back((neighbour, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's search for edges with non-negative costs.
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Distances<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    dijkstra_multi([start], neighbours)
+}
+
+/// Dijkstra's search from several sources at once.
+pub fn dijkstra_multi<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Distances<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = Distances::new();
+    let mut queue = Queue::new();
+    for start in starts {
+        distances.reached.insert(start.clone(), (C::ZERO, None));
+        queue.push(C::ZERO, start);
+    }
+
+    while let Some((distance, current)) = queue.pop() {
+        if distances.distance(&current) < Some(distance) {
+            // the node was reached by a shorter path after it had been queued
+            continue;
+        }
+        for (neighbour, cost) in neighbours(&current) {
+            let new_distance = distance + cost;
+            if distances
+                .distance(&neighbour)
+                .is_none_or(|known| new_distance < known)
+            {
+                distances
+                    .reached
+                    .insert(neighbour.clone(), (new_distance, Some(current.clone())));
+                queue.push(new_distance, neighbour);
+            }
+        }
+    }
+    distances
+}
+
+/// A* search of the shortest path to the first node satisfying `is_goal`. The heuristic must
+/// never overestimate the remaining cost, otherwise the found path may be not the shortest.
+pub fn astar<N, C, I>(
+    start: N,
+    is_goal: impl Fn(&N) -> bool,
+    mut neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> C,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = Distances::new();
+    let mut queue = Queue::new();
+    distances.reached.insert(start.clone(), (C::ZERO, None));
+    queue.push(heuristic(&start), start);
+
+    while let Some((_, current)) = queue.pop() {
+        let distance = distances.distance(&current)?;
+        if is_goal(&current) {
+            return Some((distance, distances.path_to(&current)?));
+        }
+        for (neighbour, cost) in neighbours(&current) {
+            let new_distance = distance + cost;
+            if distances
+                .distance(&neighbour)
+                .is_none_or(|known| new_distance < known)
+            {
+                distances
+                    .reached
+                    .insert(neighbour.clone(), (new_distance, Some(current.clone())));
+                queue.push(new_distance + heuristic(&neighbour), neighbour);
+            }
+        }
+    }
+    None
+}
+
+/// Min-priority queue, which does not require nodes to be ordered.
+struct Queue<N, C> {
+    heap: BinaryHeap<Reverse<(C, usize)>>,
+    nodes: Vec<Option<N>>,
+}
+
+impl<N, C: Cost> Queue<N, C> {
+    fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, priority: C, node: N) {
+        self.heap.push(Reverse((priority, self.nodes.len())));
+        self.nodes.push(Some(node));
+    }
+
+    fn pop(&mut self) -> Option<(C, N)> {
+        let Reverse((priority, index)) = self.heap.pop()?;
+        Some((priority, self.nodes[index].take()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{astar, bfs, bfs_multi, dijkstra, dijkstra_multi};
+
+    const MAZE: [&str; 4] = ["S..#", ".#..", ".#.#", "...E"];
+
+    fn open_neighbours((row, column): &(usize, usize)) -> Vec<(usize, usize)> {
+        let (row, column) = (*row, *column);
+        [
+            (row.wrapping_sub(1), column),
+            (row + 1, column),
+            (row, column.wrapping_sub(1)),
+            (row, column + 1),
+        ]
+        .into_iter()
+        .filter(|(row, column)| {
+            MAZE.get(*row)
+                .and_then(|line| line.as_bytes().get(*column))
+                .is_some_and(|cell| *cell != b'#')
+        })
+        .collect()
+    }
+
+    /// Edges of a small weighted graph, where the direct edge is longer than the detour.
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_path() {
+        let distances = bfs((0, 0), open_neighbours);
+        assert_eq!(distances.distance(&(3, 3)), Some(6));
+        let path = distances.path_to(&(3, 3)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(3, 3)));
+        assert!(!distances.contains(&(0, 3)));
+        assert_eq!(distances.path_to(&(0, 3)), None);
+    }
+
+    #[test]
+    fn bfs_from_many_sources() {
+        let distances = bfs_multi([(0, 0), (3, 3)], open_neighbours);
+        assert_eq!(distances.distance(&(3, 0)), Some(3));
+        assert_eq!(distances.distance(&(1, 3)), Some(4));
+        assert_eq!(distances.path_to(&(3, 2)), Some(vec![(3, 3), (3, 2)]));
+    }
+
+    #[test]
+    fn dijkstra_path() {
+        let distances = dijkstra('a', weighted);
+        assert_eq!(distances.distance(&'d'), Some(6));
+        assert_eq!(distances.path_to(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+
+        let distances = dijkstra_multi(['a', 'c'], weighted);
+        assert_eq!(distances.distance(&'d'), Some(3));
+        assert_eq!(distances.iter().count(), 4);
+    }
+
+    #[test]
+    fn astar_path() {
+        let manhattan = |(row, column): &(usize, usize)| (3 - row) + (3 - column);
+        let (distance, path) = astar(
+            (0, 0),
+            |node| *node == (3, 3),
+            |node| {
+                open_neighbours(node)
+                    .into_iter()
+                    .map(|neighbour| (neighbour, 1))
+            },
+            manhattan,
+        )
+        .unwrap();
+        assert_eq!(distance, 6);
+        assert_eq!(path.len(), 7);
+
+        assert_eq!(
+            astar('a', |node| *node == 'd', weighted, |_| 0),
+            Some((6, vec!['a', 'b', 'c', 'd']))
+        );
+        assert_eq!(astar('d', |node| *node == 'a', weighted, |_| 0), None);
+    }
+}
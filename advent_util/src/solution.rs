@@ -0,0 +1,373 @@
+use crate::input::InputSource;
+use crate::output::{print_answers, OutputFormat};
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("Unknown part '{}', expected 1 or 2", other)),
+        }
+    }
+}
+
+/// A puzzle of a single day. Implemented by every `dayN` crate, so the runner can dispatch
+/// to any of them.
+///
+/// The input is parsed once and then shared by both parts, which return typed answers,
+/// e.g. `u32` for sums or `String` for the letters read from a screen.
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String>;
+
+    fn part_one(&self, input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(&self, input: &Self::Input) -> Self::PartTwo;
+}
+
+/// Answer of any part of any day with the type erased, so answers of different days
+/// can be stored and compared together.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn new(value: impl Display) -> Self {
+        Self(value.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// Answers with the time spent on parsing and on each part.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub parse: Duration,
+    pub answers: Vec<(Answer, Duration)>,
+}
+
+/// Object safe view of [Solution] used by the runner to keep solutions of all days in one list.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    /// Parses the input once and solves requested parts in the same order.
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>, String>;
+
+    fn solve(&self, input: &str, part: Part) -> Result<Answer, String> {
+        self.run(input, &[part])
+            .map(|mut answers| answers.remove(0))
+    }
+
+    /// Same as [DynSolution::run], but measures parsing and every part separately.
+    fn run_timed(&self, input: &str, parts: &[Part]) -> Result<Timed, String>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>, String> {
+        let input = self.parse(input)?;
+        Ok(parts
+            .iter()
+            .map(|part| match part {
+                Part::One => Answer::new(self.part_one(&input)),
+                Part::Two => Answer::new(self.part_two(&input)),
+            })
+            .collect())
+    }
+
+    fn run_timed(&self, input: &str, parts: &[Part]) -> Result<Timed, String> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let answers = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => Answer::new(self.part_one(&input)),
+                    Part::Two => Answer::new(self.part_two(&input)),
+                };
+                (answer, start.elapsed())
+            })
+            .collect();
+        Ok(Timed { parse, answers })
+    }
+}
+
+/// Options every day binary accepts, see [MainArgs].
+pub const USAGE: &str = "[--format text|json] [input]";
+
+/// Command line of the binaries of the day crates: `[--format text|json] [input]`, where
+/// the input is a path (`-` for stdin) and `dayN/input.txt` is used without it.
+/// Options of a single day are taken out of the arguments with [take_option] or [take_flag]
+/// before the rest is parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MainArgs {
+    pub input: InputSource,
+    pub format: OutputFormat,
+}
+
+impl MainArgs {
+    pub fn parse(day: u8, args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut input = None;
+        let mut format = OutputFormat::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--format" {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value of --format".to_string())?;
+                format = value.parse()?;
+            } else if let Some(value) = arg.strip_prefix("--format=") {
+                format = value.parse()?;
+            } else if arg.starts_with("--") {
+                return Err(format!("Unknown option '{}'", arg));
+            } else if input.is_none() {
+                input = Some(InputSource::from_arg(&arg));
+            } else {
+                return Err(format!("Unexpected argument '{}'", arg));
+            }
+        }
+
+        Ok(Self {
+            input: input.unwrap_or(InputSource::Day(day)),
+            format,
+        })
+    }
+
+    /// Exits with the usage if the command line is wrong.
+    pub fn from_env(day: u8) -> Self {
+        Self::parse(day, env::args().skip(1)).unwrap_or_else(|err| exit_with_usage(day, "", &err))
+    }
+}
+
+/// Removes `<name> <value>` or `<name>=<value>` (e.g. `--viz=ansi`) from the arguments, so
+/// the rest can be parsed by [MainArgs::parse].
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(index) = args.iter().position(|arg| {
+        arg == name
+            || arg
+                .strip_prefix(name)
+                .is_some_and(|rest| rest.starts_with('='))
+    }) else {
+        return Ok(None);
+    };
+    let arg = args.remove(index);
+    match arg
+        .strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix('='))
+    {
+        Some(value) => Ok(Some(value.to_string())),
+        None if index < args.len() => Ok(Some(args.remove(index))),
+        None => Err(format!("Missing value of {}", name)),
+    }
+}
+
+/// Removes every `name` flag from the arguments, `true` if there was any.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let count = args.len();
+    args.retain(|arg| arg != name);
+    args.len() < count
+}
+
+/// Prints the error and the usage of the day binary with its own `options` in front of the
+/// common ones, then exits with code 2.
+pub fn exit_with_usage(day: u8, options: &str, error: &str) -> ! {
+    eprintln!("error: {}", error);
+    eprintln!("Usage: day{} {}{}", day, options, USAGE);
+    process::exit(2)
+}
+
+/// Prints the error of reading or solving the input and exits with code 1.
+pub fn exit_with_error(error: impl Display) -> ! {
+    eprintln!("error: {}", error);
+    process::exit(1)
+}
+
+/// Entry point for the binaries of the day crates: prints both parts for the input given
+/// by [MainArgs] from the command line.
+pub fn run_main(solution: &dyn DynSolution) {
+    run_with_args(solution, &MainArgs::from_env(solution.day()));
+}
+
+pub fn run_with_args(solution: &dyn DynSolution, args: &MainArgs) {
+    let input = args.input.read().unwrap_or_else(|err| exit_with_error(err));
+    let timed = solution
+        .run_timed(&input, &Part::ALL)
+        .unwrap_or_else(|err| exit_with_error(err));
+    print_answers(solution.day(), &Part::ALL, &timed, args.format);
+}
+
+pub fn print_answer(day: u8, part: Part, answer: &Answer) {
+    if answer.as_str().contains('\n') {
+        // multiline answers (e.g. CRT screens) are printed from the new line to keep them aligned
+        println!("Day {} part {}:\n{}", day, part, answer);
+    } else {
+        println!("Day {} part {}: {}", day, part, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::InputSource;
+    use crate::output::OutputFormat;
+    use crate::solution::{take_flag, take_option, Answer, DynSolution, MainArgs, Part, Solution};
+    use std::path::PathBuf;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = String;
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn parse(&self, input: &str) -> Result<Self::Input, String> {
+            input
+                .split_whitespace()
+                .map(|number| number.parse().map_err(|err| format!("{}", err)))
+                .collect()
+        }
+
+        fn part_one(&self, input: &Self::Input) -> Self::PartOne {
+            input.iter().sum()
+        }
+
+        fn part_two(&self, input: &Self::Input) -> Self::PartTwo {
+            format!("{:?}", input)
+        }
+    }
+
+    #[test]
+    fn typed_answers() {
+        let input = Sum.parse("1 2 3").unwrap();
+        assert_eq!(Sum.part_one(&input), 6);
+        assert_eq!(Sum.part_two(&input), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn dyn_solution_runs_requested_parts() {
+        let solution: &dyn DynSolution = &Sum;
+        assert_eq!(
+            solution.run("4 5", &[Part::Two, Part::One]),
+            Ok(vec![Answer::from("[4, 5]"), Answer::from("9")])
+        );
+        assert_eq!(solution.solve("4 5", Part::One), Ok(Answer::new(9)));
+    }
+
+    #[test]
+    fn dyn_solution_reports_parse_error() {
+        assert!(Sum.solve("4 x", Part::One).is_err());
+    }
+
+    #[test]
+    fn parse_main_args() {
+        let parse = |args: &[&str]| MainArgs::parse(3, args.iter().map(|arg| arg.to_string()));
+        assert_eq!(
+            parse(&[]),
+            Ok(MainArgs {
+                input: InputSource::Day(3),
+                format: OutputFormat::Text
+            })
+        );
+        assert_eq!(
+            parse(&["--format", "json", "-"]),
+            Ok(MainArgs {
+                input: InputSource::Stdin,
+                format: OutputFormat::Json
+            })
+        );
+        assert_eq!(
+            parse(&["in.txt", "--format=json"]),
+            Ok(MainArgs {
+                input: InputSource::Path(PathBuf::from("in.txt")),
+                format: OutputFormat::Json
+            })
+        );
+        assert!(parse(&["--format"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert!(parse(&["--top", "3"]).is_err());
+    }
+
+    #[test]
+    fn take_day_options() {
+        let mut args = [
+            "--top",
+            "3",
+            "in.txt",
+            "--scoring=a.toml",
+            "--median",
+            "--topology",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(take_option(&mut args, "--top"), Ok(Some("3".to_string())));
+        assert_eq!(
+            take_option(&mut args, "--scoring"),
+            Ok(Some("a.toml".to_string()))
+        );
+        assert_eq!(take_option(&mut args, "--scoring"), Ok(None));
+        assert!(take_flag(&mut args, "--median"));
+        assert!(!take_flag(&mut args, "--median"));
+        assert_eq!(args, ["in.txt", "--topology"]);
+
+        let mut args = vec!["in.txt".to_string(), "--top".to_string()];
+        assert!(take_option(&mut args, "--top").is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! Unbounded grid addressed by [Point2], which grows in any direction. Rows go down along `y`
+//! like in puzzle maps.
+
+use crate::point::Point2;
+use std::collections::HashMap;
+
+/// Side of the square chunks the cells are stored in.
+const CHUNK_SIZE: isize = 16;
+
+/// Inclusive bounding box of points.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Bounds {
+    pub fn of(point: Point2) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        point.within(self.min, self.max)
+    }
+
+    /// The smallest bounds containing both these bounds and the point.
+    pub fn include(&self, point: Point2) -> Self {
+        Self {
+            min: self.min.component_min(point),
+            max: self.max.component_max(point),
+        }
+    }
+}
+
+/// Sparse grid which keeps only occupied cells. Cells are stored in chunks, so neighbouring
+/// cells are close in memory and the grid can grow without moving existing cells.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    chunks: HashMap<Point2, Vec<Option<T>>>,
+    bounds: Option<Bounds>,
+    len: usize,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            chunks: HashMap::new(),
+            bounds: None,
+            len: 0,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Bounds of all points ever occupied, they do not shrink when cells are removed.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, point: Point2) -> Option<&T> {
+        let (chunk, index) = Self::locate(point);
+        self.chunks.get(&chunk)?[index].as_ref()
+    }
+
+    pub fn get_mut(&mut self, point: Point2) -> Option<&mut T> {
+        let (chunk, index) = Self::locate(point);
+        self.chunks.get_mut(&chunk)?[index].as_mut()
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// Occupies the cell and returns its previous value.
+    pub fn insert(&mut self, point: Point2, value: T) -> Option<T> {
+        let (chunk, index) = Self::locate(point);
+        let previous = self
+            .chunks
+            .entry(chunk)
+            .or_insert_with(|| (0..CHUNK_SIZE * CHUNK_SIZE).map(|_| None).collect())[index]
+            .replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        self.bounds = Some(
+            self.bounds
+                .map_or(Bounds::of(point), |bounds| bounds.include(point)),
+        );
+        previous
+    }
+
+    pub fn remove(&mut self, point: Point2) -> Option<T> {
+        let (chunk, index) = Self::locate(point);
+        let previous = self.chunks.get_mut(&chunk)?[index].take();
+        if previous.is_some() {
+            self.len -= 1;
+        }
+        previous
+    }
+
+    /// Occupied cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.chunks.iter().flat_map(|(chunk, cells)| {
+            cells.iter().enumerate().filter_map(move |(index, cell)| {
+                let index = index as isize;
+                let point =
+                    *chunk * CHUNK_SIZE + Point2::new(index % CHUNK_SIZE, index / CHUNK_SIZE);
+                Some((point, cell.as_ref()?))
+            })
+        })
+    }
+
+    /// Text with a character per cell of the bounding box and a line per row.
+    pub fn render(&self, cell: impl Fn(Option<&T>) -> char) -> String {
+        match self.bounds {
+            Some(bounds) => self.render_within(bounds, cell),
+            None => String::new(),
+        }
+    }
+
+    /// Text with a character per cell of the bounds, which may be larger or smaller than
+    /// the grid.
+    pub fn render_within(&self, bounds: Bounds, cell: impl Fn(Option<&T>) -> char) -> String {
+        (bounds.min.y..=bounds.max.y)
+            .map(|y| {
+                (bounds.min.x..=bounds.max.x)
+                    .map(|x| cell(self.get(Point2::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Chunk of the point and the index of the point inside of it.
+    fn locate(point: Point2) -> (Point2, usize) {
+        let chunk = Point2::new(
+            point.x.div_euclid(CHUNK_SIZE),
+            point.y.div_euclid(CHUNK_SIZE),
+        );
+        let index = point.y.rem_euclid(CHUNK_SIZE) * CHUNK_SIZE + point.x.rem_euclid(CHUNK_SIZE);
+        (chunk, index as usize)
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point2;
+    use crate::sparse_grid::{Bounds, SparseGrid};
+
+    #[test]
+    fn grow_in_all_directions() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.insert(Point2::new(0, 0), 'a'), None);
+        assert_eq!(grid.insert(Point2::new(3, -20), 'b'), None);
+        assert_eq!(grid.insert(Point2::new(-40, 5), 'c'), None);
+        assert_eq!(grid.insert(Point2::new(0, 0), 'd'), Some('a'));
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(Point2::new(0, 0)), Some(&'d'));
+        assert_eq!(grid.get(Point2::new(3, -20)), Some(&'b'));
+        assert_eq!(grid.get(Point2::new(-40, 5)), Some(&'c'));
+        assert_eq!(grid.get(Point2::new(0, 1)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Point2::new(-40, -20),
+                max: Point2::new(3, 5)
+            })
+        );
+
+        assert_eq!(grid.remove(Point2::new(3, -20)), Some('b'));
+        assert_eq!(grid.remove(Point2::new(3, -20)), None);
+        assert_eq!(grid.len(), 2);
+
+        let mut points = grid.iter().map(|(point, _)| point).collect::<Vec<_>>();
+        points.sort();
+        assert_eq!(points, vec![Point2::new(-40, 5), Point2::new(0, 0)]);
+    }
+
+    #[test]
+    fn render_bounding_box() {
+        let grid = [(Point2::new(-1, -1), '#'), (Point2::new(1, 0), 'o')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+        assert_eq!(grid.render(|cell| *cell.unwrap_or(&'.')), "#..\n..o");
+        assert_eq!(SparseGrid::<char>::new().render(|_| '.'), "");
+        assert_eq!(
+            grid.render_within(
+                Bounds {
+                    min: Point2::new(0, 0),
+                    max: Point2::new(2, 0)
+                },
+                |cell| *cell.unwrap_or(&'.')
+            ),
+            ".o."
+        );
+    }
+}
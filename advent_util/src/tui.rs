@@ -0,0 +1,311 @@
+//! Terminal explorer of simulations, which steps them forward and back, plays and pauses them
+//! and shows their state next to the map. It works over SSH, unlike windowed renderers.
+
+use crate::solution::{exit_with_usage, take_flag, MainArgs};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::env;
+use std::io;
+use std::time::Duration;
+
+/// Simulation going step by step, e.g. moving a rope or dropping sand.
+pub trait Simulation: Clone {
+    /// Advances by one step, `false` without any changes if the simulation is finished.
+    fn step(&mut self) -> bool;
+
+    /// Map of the current state.
+    fn render(&self) -> String;
+
+    /// Named values of the current state.
+    fn status(&self) -> Vec<(String, String)>;
+}
+
+/// States are restored from the closest earlier checkpoint when stepping back.
+const CHECKPOINT_EVERY: usize = 256;
+/// Steps done by jumping to the end at most, so endless simulations do not hang.
+const MAX_STEPS: usize = 1_000_000;
+const TICK: Duration = Duration::from_millis(50);
+const HELP: [&str; 8] = [
+    "→ n     step forward",
+    "← p     step back",
+    "] [     100 steps",
+    "Home End  jump",
+    "space   play / pause",
+    "+ -     speed",
+    "w a s d scroll",
+    "q Esc   quit",
+];
+
+/// Simulation with its history.
+pub struct Explorer<S> {
+    checkpoints: Vec<S>,
+    current: S,
+    step: usize,
+    /// Number of steps of the simulation, known once it is finished.
+    last_step: Option<usize>,
+}
+
+impl<S: Simulation> Explorer<S> {
+    pub fn new(simulation: S) -> Self {
+        Self {
+            checkpoints: vec![simulation.clone()],
+            current: simulation,
+            step: 0,
+            last_step: None,
+        }
+    }
+
+    pub fn current(&self) -> &S {
+        &self.current
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn last_step(&self) -> Option<usize> {
+        self.last_step
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.last_step == Some(self.step)
+    }
+
+    pub fn forward(&mut self, steps: usize) {
+        for _ in 0..steps {
+            if self.is_finished() {
+                break;
+            }
+            if !self.current.step() {
+                self.last_step = Some(self.step);
+                break;
+            }
+            self.step += 1;
+            if self.step.is_multiple_of(CHECKPOINT_EVERY)
+                && self.checkpoints.len() == self.step / CHECKPOINT_EVERY
+            {
+                self.checkpoints.push(self.current.clone());
+            }
+        }
+    }
+
+    pub fn back(&mut self, steps: usize) {
+        self.jump_to(self.step.saturating_sub(steps));
+    }
+
+    pub fn jump_to(&mut self, step: usize) {
+        if step < self.step {
+            let checkpoint = step / CHECKPOINT_EVERY;
+            self.current = self.checkpoints[checkpoint].clone();
+            self.step = checkpoint * CHECKPOINT_EVERY;
+        }
+        self.forward(step - self.step);
+    }
+
+    pub fn jump_to_end(&mut self) {
+        self.jump_to(MAX_STEPS.max(self.step));
+    }
+}
+
+/// [MainArgs] of the command line if it has `--tui`, which asks to explore the simulation.
+pub fn from_env(day: u8) -> Option<MainArgs> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    if !take_flag(&mut args, "--tui") {
+        return None;
+    }
+    Some(MainArgs::parse(day, args).unwrap_or_else(|err| exit_with_usage(day, "--tui ", &err)))
+}
+
+/// Takes over the terminal until the user quits.
+pub fn explore<S: Simulation>(title: &str, simulation: S) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = App::new(title, simulation).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+struct App<S> {
+    title: String,
+    explorer: Explorer<S>,
+    playing: bool,
+    /// Steps per tick while playing.
+    speed: usize,
+    /// Rows and columns of the map hidden above and to the left.
+    scroll: (u16, u16),
+}
+
+impl<S: Simulation> App<S> {
+    fn new(title: &str, simulation: S) -> Self {
+        Self {
+            title: title.to_string(),
+            explorer: Explorer::new(simulation),
+            playing: false,
+            speed: 1,
+            scroll: (0, 0),
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if event::poll(TICK)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press && !self.handle_key(key.code) {
+                        return Ok(());
+                    }
+                }
+            } else if self.playing {
+                self.explorer.forward(self.speed);
+                self.playing = !self.explorer.is_finished();
+            }
+        }
+    }
+
+    /// `false` if the user quits.
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        let (row, column) = self.scroll;
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Right | KeyCode::Char('n') => self.explorer.forward(1),
+            KeyCode::Left | KeyCode::Char('p') => self.explorer.back(1),
+            KeyCode::Char(']') => self.explorer.forward(100),
+            KeyCode::Char('[') => self.explorer.back(100),
+            KeyCode::Home => self.explorer.jump_to(0),
+            KeyCode::End => self.explorer.jump_to_end(),
+            KeyCode::Char(' ') => self.playing = !self.playing && !self.explorer.is_finished(),
+            KeyCode::Char('+') => self.speed = (self.speed * 2).min(1024),
+            KeyCode::Char('-') => self.speed = (self.speed / 2).max(1),
+            KeyCode::Char('w') => self.scroll = (row.saturating_sub(5), column),
+            KeyCode::Char('s') => self.scroll = (row.saturating_add(5), column),
+            KeyCode::Char('a') => self.scroll = (row, column.saturating_sub(5)),
+            KeyCode::Char('d') => self.scroll = (row, column.saturating_add(5)),
+            _ => {}
+        }
+        true
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [map_area, side_area] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(34)]).areas(frame.area());
+
+        let map = Paragraph::new(self.explorer.current().render())
+            .scroll(self.scroll)
+            .block(Block::bordered().title(self.title.as_str()));
+        frame.render_widget(map, map_area);
+
+        let step = match self.explorer.last_step() {
+            Some(last_step) => format!("Step {} of {}", self.explorer.step(), last_step),
+            None => format!("Step {}", self.explorer.step()),
+        };
+        let state = if self.playing {
+            format!("Playing x{}", self.speed)
+        } else {
+            format!("Paused, speed x{}", self.speed)
+        };
+        let lines = [step, state, String::new()]
+            .into_iter()
+            .chain(
+                self.explorer
+                    .current()
+                    .status()
+                    .into_iter()
+                    .map(|(name, value)| format!("{}: {}", name, value)),
+            )
+            .chain([String::new()])
+            .chain(HELP.map(String::from))
+            .map(Line::from)
+            .collect::<Vec<_>>();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title("State")),
+            side_area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tui::{App, Explorer, Simulation, CHECKPOINT_EVERY};
+    use ratatui::backend::TestBackend;
+    use ratatui::crossterm::event::KeyCode;
+    use ratatui::Terminal;
+
+    /// Counts up to the limit.
+    #[derive(Clone)]
+    struct Counter {
+        value: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            self.value += 1;
+            if self.value > self.limit {
+                self.value = self.limit;
+                return false;
+            }
+            true
+        }
+
+        fn render(&self) -> String {
+            "#".repeat(self.value)
+        }
+
+        fn status(&self) -> Vec<(String, String)> {
+            vec![("Value".to_string(), self.value.to_string())]
+        }
+    }
+
+    fn counter(limit: usize) -> Counter {
+        Counter { value: 0, limit }
+    }
+
+    #[test]
+    fn step_forward_and_back() {
+        let mut explorer = Explorer::new(counter(1000));
+        explorer.forward(600);
+        assert_eq!((explorer.step(), explorer.current().value), (600, 600));
+        explorer.back(450);
+        assert_eq!((explorer.step(), explorer.current().value), (150, 150));
+        explorer.jump_to(CHECKPOINT_EVERY + 1);
+        assert_eq!(explorer.current().value, CHECKPOINT_EVERY + 1);
+
+        explorer.jump_to_end();
+        assert!(explorer.is_finished());
+        assert_eq!(explorer.last_step(), Some(1000));
+        explorer.forward(1);
+        assert_eq!(explorer.current().value, 1000);
+        explorer.back(2000);
+        assert_eq!((explorer.step(), explorer.current().value), (0, 0));
+    }
+
+    #[test]
+    fn keys_and_drawing() {
+        let mut app = App::new("Counter", counter(3));
+        assert!(app.handle_key(KeyCode::Right));
+        assert!(app.handle_key(KeyCode::Char(']')));
+        assert!(app.handle_key(KeyCode::Left));
+        assert_eq!(app.explorer.step(), 2);
+        assert!(app.handle_key(KeyCode::Char('+')));
+        assert!(app.handle_key(KeyCode::Char(' ')));
+        assert!(app.playing);
+        assert!(!app.handle_key(KeyCode::Char('q')));
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let screen = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        assert!(screen.contains("Counter"));
+        assert!(screen.contains("##"));
+        assert!(screen.contains("Step 2 of 3"));
+        assert!(screen.contains("Value: 2"));
+        assert!(screen.contains("Playing x2"));
+    }
+}
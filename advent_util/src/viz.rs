@@ -0,0 +1,389 @@
+//! Animations of character frames like the ones of `render` functions, which are written
+//! as an animated GIF, a sequence of PNG files or played in the terminal with ANSI colours.
+
+use crate::grid::Grid;
+use crate::solution::{exit_with_usage, take_option, MainArgs};
+use std::borrow::Cow;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+pub type Rgb = [u8; 3];
+
+/// Colours of frame characters. Characters without a colour are drawn with the background
+/// colour if they are `.` or ` `, and with the foreground colour otherwise.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    background: Rgb,
+    foreground: Rgb,
+    colors: Vec<(char, Rgb)>,
+}
+
+impl Palette {
+    pub fn new(background: Rgb, foreground: Rgb) -> Self {
+        Self {
+            background,
+            foreground,
+            colors: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, cell: char, color: Rgb) -> Self {
+        self.colors.retain(|(c, _)| *c != cell);
+        self.colors.push((cell, color));
+        self
+    }
+
+    pub fn color(&self, cell: char) -> Rgb {
+        match self.colors.iter().find(|(c, _)| *c == cell) {
+            Some((_, color)) => *color,
+            None if cell == '.' || cell == ' ' => self.background,
+            None => self.foreground,
+        }
+    }
+
+    /// Index of the colour in [Palette::all_colors].
+    fn index(&self, cell: char) -> usize {
+        match self.colors.iter().position(|(c, _)| *c == cell) {
+            Some(position) => position + 2,
+            None if cell == '.' || cell == ' ' => 0,
+            None => 1,
+        }
+    }
+
+    fn all_colors(&self) -> Vec<Rgb> {
+        [self.background, self.foreground]
+            .into_iter()
+            .chain(self.colors.iter().map(|(_, color)| *color))
+            .collect()
+    }
+}
+
+impl Default for Palette {
+    /// Light grey on a dark background.
+    fn default() -> Self {
+        Self::new([16, 16, 32], [200, 200, 200])
+    }
+}
+
+/// Where to write an animation, parsed from `ansi`, a `.gif` path or a directory for PNG files.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum VizTarget {
+    Gif(PathBuf),
+    Png(PathBuf),
+    Ansi,
+}
+
+impl VizTarget {
+    /// Options of [VizTarget::from_env] in front of the ones of [MainArgs].
+    pub const USAGE: &'static str = "[--viz ansi|FILE.gif|DIR] ";
+
+    /// Removes `--viz <target>` or `--viz=<target>` from the arguments, so the rest can be
+    /// parsed by [crate::solution::MainArgs].
+    pub fn take_from_args(args: &mut Vec<String>) -> Result<Option<Self>, String> {
+        take_option(args, "--viz")?
+            .map(|value| value.parse())
+            .transpose()
+    }
+
+    /// Command line arguments of a day binary, which can visualise the solution.
+    pub fn from_env(day: u8) -> (MainArgs, Option<Self>) {
+        let mut args = env::args().skip(1).collect();
+        let target = Self::take_from_args(&mut args)
+            .unwrap_or_else(|err| exit_with_usage(day, Self::USAGE, &err));
+        let args = MainArgs::parse(day, args)
+            .unwrap_or_else(|err| exit_with_usage(day, Self::USAGE, &err));
+        (args, target)
+    }
+}
+
+impl FromStr for VizTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("Expected 'ansi', a .gif file or a directory for PNG files".to_string()),
+            "ansi" => Ok(VizTarget::Ansi),
+            path if path.ends_with(".gif") => Ok(VizTarget::Gif(PathBuf::from(path))),
+            path => Ok(VizTarget::Png(PathBuf::from(path))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Animation {
+    palette: Palette,
+    frames: Vec<Grid<char>>,
+    /// Side of the square of pixels drawn for a cell.
+    scale: usize,
+    delay: Duration,
+}
+
+impl Animation {
+    pub fn new(palette: Palette) -> Self {
+        Self {
+            palette,
+            frames: Vec::new(),
+            scale: 4,
+            delay: Duration::from_millis(100),
+        }
+    }
+
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Frames smaller than the largest one are padded with the background to the right
+    /// and to the bottom.
+    pub fn push(&mut self, frame: Grid<char>) {
+        self.frames.push(frame);
+    }
+
+    /// Frame of a text with a line per row.
+    pub fn push_text(&mut self, text: &str) {
+        let width = text.lines().map(|line| line.chars().count()).max();
+        let rows = text
+            .lines()
+            .map(|line| {
+                let mut row = line.chars().collect::<Vec<_>>();
+                row.resize(width.unwrap_or(0), ' ');
+                row
+            })
+            .collect();
+        self.push(Grid::from_rows(rows).expect("rows are padded to the same width"));
+    }
+
+    pub fn write(&self, target: &VizTarget) -> Result<(), String> {
+        match target {
+            VizTarget::Gif(path) => self.write_gif(path),
+            VizTarget::Png(dir) => self.write_png_sequence(dir).map(|_| ()),
+            VizTarget::Ansi => self
+                .play_ansi(&mut io::stdout().lock())
+                .map_err(|err| format!("Cannot play animation: {}", err)),
+        }
+    }
+
+    pub fn write_gif(&self, path: &Path) -> Result<(), String> {
+        let error = |err: gif::EncodingError| format!("Cannot write '{}': {}", path.display(), err);
+        let colors = self.palette.all_colors();
+        if colors.len() > 256 {
+            return Err(format!(
+                "GIF supports up to 256 colours, but {} are used",
+                colors.len()
+            ));
+        }
+        let (height, width) = self.pixel_size();
+        let (height, width) = (
+            u16::try_from(height).map_err(|_| "Frames are too high for GIF".to_string())?,
+            u16::try_from(width).map_err(|_| "Frames are too wide for GIF".to_string())?,
+        );
+
+        let file = create_file(path)?;
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &colors.concat()).map_err(error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(error)?;
+        for frame in &self.frames {
+            let pixels = self
+                .pixels(frame)
+                .into_iter()
+                .map(|index| index as u8)
+                .collect::<Vec<_>>();
+            let frame = gif::Frame {
+                width,
+                height,
+                buffer: Cow::Owned(pixels),
+                // in hundredths of a second
+                delay: (self.delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(error)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `frame0000.png`, `frame0001.png`, etc. into the directory.
+    pub fn write_png_sequence(&self, dir: &Path) -> Result<Vec<PathBuf>, String> {
+        let colors = self.palette.all_colors();
+        let (height, width) = self.pixel_size();
+
+        let mut paths = Vec::new();
+        for (i, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame{:04}.png", i));
+            let error =
+                |err: png::EncodingError| format!("Cannot write '{}': {}", path.display(), err);
+
+            let mut encoder = png::Encoder::new(create_file(&path)?, width as u32, height as u32);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header().map_err(error)?;
+            let data = self
+                .pixels(frame)
+                .into_iter()
+                .flat_map(|index| colors[index])
+                .collect::<Vec<_>>();
+            writer.write_image_data(&data).map_err(error)?;
+            writer.finish().map_err(error)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Draws frames one over another with two spaces on a coloured background per cell.
+    pub fn play_ansi(&self, out: &mut impl Write) -> io::Result<()> {
+        let (height, width) = self.size();
+        // clear the screen once, later frames only move the cursor back
+        write!(out, "\x1b[2J")?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            if i > 0 {
+                thread::sleep(self.delay);
+            }
+            write!(out, "\x1b[H")?;
+            for row in 0..height {
+                for column in 0..width {
+                    let [r, g, b] = self.palette.color(self.cell(frame, (row, column)));
+                    write!(out, "\x1b[48;2;{};{};{}m  ", r, g, b)?;
+                }
+                writeln!(out, "\x1b[0m")?;
+            }
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Size in cells of the largest frame.
+    fn size(&self) -> (usize, usize) {
+        self.frames.iter().fold((0, 0), |(height, width), frame| {
+            (height.max(frame.height()), width.max(frame.width()))
+        })
+    }
+
+    fn pixel_size(&self) -> (usize, usize) {
+        let (height, width) = self.size();
+        (height * self.scale, width * self.scale)
+    }
+
+    fn cell(&self, frame: &Grid<char>, position: (usize, usize)) -> char {
+        frame.get(position).copied().unwrap_or(' ')
+    }
+
+    /// Palette indices of the pixels row by row.
+    fn pixels(&self, frame: &Grid<char>) -> Vec<usize> {
+        let (height, width) = self.pixel_size();
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (y / self.scale, x / self.scale)))
+            .map(|position| self.palette.index(self.cell(frame, position)))
+            .collect()
+    }
+}
+
+fn create_file(path: &Path) -> Result<BufWriter<File>, String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Cannot create '{}': {}", dir.display(), err))?;
+    }
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|err| format!("Cannot create '{}': {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::viz::{Animation, Palette, VizTarget};
+    use std::env::temp_dir;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+    use std::time::Duration;
+
+    fn animation() -> Animation {
+        let mut animation = Animation::new(Palette::default().with('#', [255, 0, 0]))
+            .scale(2)
+            .delay(Duration::ZERO);
+        animation.push_text("#.\n.");
+        animation.push_text("..#\n#");
+        animation
+    }
+
+    #[test]
+    fn palette_colors() {
+        let palette = Palette::new([0, 0, 0], [9, 9, 9]).with('o', [1, 2, 3]);
+        assert_eq!(palette.color('o'), [1, 2, 3]);
+        assert_eq!(palette.color('.'), [0, 0, 0]);
+        assert_eq!(palette.color('x'), [9, 9, 9]);
+    }
+
+    #[test]
+    fn frames_are_padded() {
+        let animation = animation();
+        assert_eq!(animation.len(), 2);
+        assert_eq!(animation.pixel_size(), (4, 6));
+        // the first frame has no third column, so it is filled with the background
+        assert_eq!(
+            animation.pixels(&animation.frames[0])[..6],
+            [2, 2, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn write_files() {
+        let dir = temp_dir().join(format!("advent_util_viz_{}", process::id()));
+        let animation = animation();
+
+        let gif = dir.join("animation.gif");
+        animation.write_gif(&gif).unwrap();
+        assert!(fs::read(&gif).unwrap().starts_with(b"GIF89a"));
+
+        let paths = animation.write_png_sequence(&dir.join("png")).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert!(fs::read(&paths[1]).unwrap().starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn play_in_terminal() {
+        let mut out = Vec::new();
+        animation().play_ansi(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[H").count(), 2);
+        assert!(out.contains("\x1b[48;2;255;0;0m  "));
+    }
+
+    #[test]
+    fn parse_target() {
+        let mut args = ["--viz", "out.gif", "input.txt"].map(String::from).to_vec();
+        assert_eq!(
+            VizTarget::take_from_args(&mut args),
+            Ok(Some(VizTarget::Gif(PathBuf::from("out.gif"))))
+        );
+        assert_eq!(args, vec!["input.txt"]);
+
+        let mut args = vec!["--viz=ansi".to_string()];
+        assert_eq!(
+            VizTarget::take_from_args(&mut args),
+            Ok(Some(VizTarget::Ansi))
+        );
+        assert_eq!(VizTarget::take_from_args(&mut args), Ok(None));
+        assert_eq!(
+            "frames".parse(),
+            Ok(VizTarget::Png(PathBuf::from("frames")))
+        );
+        assert!(VizTarget::take_from_args(&mut vec!["--viz".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Fixed-size windows and groups over slices and iterators.
+
+use std::collections::VecDeque;
+use std::iter::Fuse;
+
+/// Overlapping windows of `N` elements, e.g. `[1, 2]`, `[2, 3]` for `[1, 2, 3]`.
+pub fn windows<T, const N: usize>(slice: &[T]) -> impl Iterator<Item = &[T; N]> {
+    slice
+        .windows(N)
+        .map(|window| window.try_into().expect("windows have N elements"))
+}
+
+/// Non-overlapping chunks of `N` elements, the remainder shorter than `N` is skipped.
+pub fn chunks<T, const N: usize>(slice: &[T]) -> impl Iterator<Item = &[T; N]> {
+    slice
+        .chunks_exact(N)
+        .map(|chunk| chunk.try_into().expect("chunks have N elements"))
+}
+
+/// Overlapping mutable windows of `N` elements, see [WindowsMut].
+pub fn windows_mut<T, const N: usize>(slice: &mut [T]) -> WindowsMut<'_, T, N> {
+    WindowsMut { slice, start: 0 }
+}
+
+/// Every element with the next one, and the last element with the first one.
+pub fn pairs_cyclic<T>(slice: &[T]) -> impl Iterator<Item = (&T, &T)> {
+    slice.iter().pairs_cyclic()
+}
+
+/// Mutable windows can overlap only if the previous one is no longer used, so they are
+/// handed out one by one instead of by an [Iterator].
+pub struct WindowsMut<'a, T, const N: usize> {
+    slice: &'a mut [T],
+    start: usize,
+}
+
+impl<T, const N: usize> WindowsMut<'_, T, N> {
+    pub fn next_window(&mut self) -> Option<&mut [T; N]> {
+        let window = self.slice.get_mut(self.start..self.start + N)?;
+        self.start += 1;
+        Some(window.try_into().expect("windows have N elements"))
+    }
+
+    pub fn for_each(mut self, mut f: impl FnMut(&mut [T; N])) {
+        while let Some(window) = self.next_window() {
+            f(window);
+        }
+    }
+}
+
+/// Windows and groups of elements of any iterator, which do not need the elements collected first.
+pub trait WindowsExt: Iterator + Sized {
+    /// Overlapping windows of `N` elements, see [windows].
+    fn sliding<const N: usize>(self) -> Sliding<Self, N>
+    where
+        Self::Item: Clone,
+    {
+        Sliding {
+            iter: self,
+            window: VecDeque::with_capacity(N),
+        }
+    }
+
+    /// Non-overlapping chunks of `N` elements, see [chunks].
+    fn chunked<const N: usize>(self) -> Chunked<Self, N> {
+        Chunked { iter: self }
+    }
+
+    /// Every element with the next one, and the last element with the first one.
+    fn pairs_cyclic(self) -> PairsCyclic<Self>
+    where
+        Self::Item: Clone,
+    {
+        PairsCyclic {
+            iter: self.fuse(),
+            first: None,
+            previous: None,
+        }
+    }
+
+    /// Groups of elements between separators, like [str::split] does for text.
+    fn split_on<P>(self, is_separator: P) -> SplitOn<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        SplitOn {
+            iter: self,
+            is_separator,
+            finished: false,
+        }
+    }
+}
+
+impl<I: Iterator> WindowsExt for I {}
+
+pub struct Sliding<I: Iterator, const N: usize> {
+    iter: I,
+    window: VecDeque<I::Item>,
+}
+
+impl<I, const N: usize> Iterator for Sliding<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == N {
+            self.window.pop_front();
+        }
+        while self.window.len() < N {
+            self.window.push_back(self.iter.next()?);
+        }
+        Some(std::array::from_fn(|i| self.window[i].clone()))
+    }
+}
+
+pub struct Chunked<I, const N: usize> {
+    iter: I,
+}
+
+impl<I: Iterator, const N: usize> Iterator for Chunked<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.by_ref().take(N).collect::<Vec<_>>();
+        chunk.try_into().ok()
+    }
+}
+
+pub struct PairsCyclic<I: Iterator> {
+    iter: Fuse<I>,
+    first: Option<I::Item>,
+    previous: Option<I::Item>,
+}
+
+impl<I> Iterator for PairsCyclic<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.previous.is_none() {
+            let first = self.iter.next()?;
+            self.first = Some(first.clone());
+            self.previous = Some(first);
+        }
+        match self.iter.next() {
+            Some(item) => Some((self.previous.replace(item.clone())?, item)),
+            None => Some((self.previous.take()?, self.first.take()?)),
+        }
+    }
+}
+
+pub struct SplitOn<I, P> {
+    iter: I,
+    is_separator: P,
+    finished: bool,
+}
+
+impl<I, P> Iterator for SplitOn<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let mut group = Vec::new();
+        for item in self.iter.by_ref() {
+            if (self.is_separator)(&item) {
+                return Some(group);
+            }
+            group.push(item);
+        }
+        self.finished = true;
+        Some(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::windows::{chunks, pairs_cyclic, windows, windows_mut, WindowsExt};
+
+    #[test]
+    fn slice_windows() {
+        let numbers = [1, 2, 3, 4, 5];
+        assert_eq!(
+            windows::<_, 2>(&numbers).collect::<Vec<_>>(),
+            vec![&[1, 2], &[2, 3], &[3, 4], &[4, 5]]
+        );
+        assert_eq!(
+            chunks::<_, 2>(&numbers).collect::<Vec<_>>(),
+            vec![&[1, 2], &[3, 4]]
+        );
+        assert_eq!(windows::<_, 6>(&numbers).count(), 0);
+
+        let mut running = numbers;
+        windows_mut::<_, 2>(&mut running).for_each(|[previous, current]| *current += *previous);
+        assert_eq!(running, [1, 3, 6, 10, 15]);
+    }
+
+    #[test]
+    fn iterator_windows() {
+        assert_eq!(
+            "abcd".chars().sliding::<3>().collect::<Vec<_>>(),
+            vec![['a', 'b', 'c'], ['b', 'c', 'd']]
+        );
+        assert_eq!("ab".chars().sliding::<3>().count(), 0);
+        assert_eq!(
+            (1..=7).chunked::<3>().collect::<Vec<_>>(),
+            vec![[1, 2, 3], [4, 5, 6]]
+        );
+    }
+
+    #[test]
+    fn cyclic_pairs() {
+        assert_eq!(
+            pairs_cyclic(&[1, 2, 3]).collect::<Vec<_>>(),
+            vec![(&1, &2), (&2, &3), (&3, &1)]
+        );
+        assert_eq!(pairs_cyclic(&[1]).collect::<Vec<_>>(), vec![(&1, &1)]);
+        assert_eq!(pairs_cyclic::<u8>(&[]).count(), 0);
+    }
+
+    #[test]
+    fn split_on_separator() {
+        assert_eq!(
+            "1\n2\n\n3"
+                .lines()
+                .split_on(|line| line.is_empty())
+                .collect::<Vec<_>>(),
+            vec![vec!["1", "2"], vec!["3"]]
+        );
+        assert_eq!(
+            "".lines()
+                .split_on(|line| line.is_empty())
+                .collect::<Vec<_>>(),
+            vec![Vec::<&str>::new()]
+        );
+    }
+}
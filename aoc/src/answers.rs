@@ -0,0 +1,163 @@
+use advent_util::solution::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Answers given by solutions before, relative to the workspace root.
+pub const ANSWERS_FILE: &str = ".aoc/answers.toml";
+
+/// Hash of the input, so answers to different inputs of the same day are kept apart.
+/// FNV-1a is used, because unlike [std::hash::DefaultHasher] it is the same in every build.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StoredAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+/// How a new answer relates to the stored one for the same input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Comparison {
+    /// Nothing was stored for the input yet.
+    New,
+    Same,
+    Changed {
+        stored: String,
+    },
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<StoredAnswer>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| toml::from_str(&content).map_err(|err| err.to_string()))
+            .map_err(|err| format!("Cannot read answers from '{}': {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|err| err.to_string())?;
+        path.parent()
+            .map(fs::create_dir_all)
+            .transpose()
+            .and_then(|_| fs::write(path, content))
+            .map_err(|err| format!("Cannot write answers to '{}': {}", path.display(), err))
+    }
+
+    fn find(&self, day: u8, part: Part, input_hash: &str) -> Option<&StoredAnswer> {
+        self.answers.iter().find(|stored| {
+            stored.day == day && stored.part == part.number() && stored.input_hash == input_hash
+        })
+    }
+
+    pub fn compare(&self, day: u8, part: Part, input_hash: &str, answer: &Answer) -> Comparison {
+        match self.find(day, part, input_hash) {
+            None => Comparison::New,
+            Some(stored) if stored.answer == answer.as_str() => Comparison::Same,
+            Some(stored) => Comparison::Changed {
+                stored: stored.answer.clone(),
+            },
+        }
+    }
+
+    /// Stores the answer, replacing the stored one for the same input.
+    pub fn record(&mut self, day: u8, part: Part, input_hash: &str, answer: &Answer) {
+        self.answers.retain(|stored| {
+            stored.day != day || stored.part != part.number() || stored.input_hash != input_hash
+        });
+        self.answers.push(StoredAnswer {
+            day,
+            part: part.number(),
+            input_hash: input_hash.to_string(),
+            answer: answer.to_string(),
+        });
+        self.answers.sort_by_key(|stored| (stored.day, stored.part));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{input_hash, Answers, Comparison};
+    use advent_util::solution::{Answer, Part};
+    use std::env::temp_dir;
+    use std::fs;
+    use std::process;
+
+    #[test]
+    fn hash_is_stable() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1\n2\n"), input_hash("1\n3\n"));
+    }
+
+    #[test]
+    fn compare_with_stored_answers() {
+        let mut answers = Answers::default();
+        let hash = input_hash("1000\n2000\n");
+        assert_eq!(
+            answers.compare(1, Part::One, &hash, &Answer::new(3000)),
+            Comparison::New
+        );
+
+        answers.record(1, Part::One, &hash, &Answer::new(3000));
+        assert_eq!(
+            answers.compare(1, Part::One, &hash, &Answer::new(3000)),
+            Comparison::Same
+        );
+        assert_eq!(
+            answers.compare(1, Part::One, &hash, &Answer::new(2000)),
+            Comparison::Changed {
+                stored: "3000".to_string()
+            }
+        );
+        assert_eq!(
+            answers.compare(1, Part::Two, &hash, &Answer::new(3000)),
+            Comparison::New
+        );
+        assert_eq!(
+            answers.compare(1, Part::One, &input_hash("other"), &Answer::new(2000)),
+            Comparison::New
+        );
+
+        answers.record(1, Part::One, &hash, &Answer::new(2000));
+        assert_eq!(
+            answers.compare(1, Part::One, &hash, &Answer::new(2000)),
+            Comparison::Same
+        );
+        assert_eq!(answers.answers.len(), 1);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = temp_dir()
+            .join(format!("aoc_answers_{}", process::id()))
+            .join("answers.toml");
+        let _ = fs::remove_file(&path);
+        assert!(Answers::load(&path).unwrap().answers.is_empty());
+
+        let mut answers = Answers::default();
+        answers.record(10, Part::Two, "abc", &Answer::from("#..\n.#."));
+        answers.record(2, Part::One, "def", &Answer::new(15));
+        answers.save(&path).unwrap();
+
+        let loaded = Answers::load(&path).unwrap();
+        assert_eq!(loaded.answers, answers.answers);
+        assert_eq!(loaded.answers[0].day, 2);
+    }
+}
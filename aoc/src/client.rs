@@ -0,0 +1,103 @@
+use crate::config::{Config, CONFIG_FILE, SESSION_VAR};
+use advent_util::solution::{Answer, Part};
+use std::time::Duration;
+use ureq::Agent;
+
+const USER_AGENT: &str = "github.com/zinoviy23/advent-of-code-2022 aoc runner";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Client of the Advent of Code site authorized with the session cookie.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    year: u16,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, year: u16, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(TIMEOUT))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year,
+            session: session.to_string(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        Ok(Self::new(
+            config.base_url(),
+            config.year(),
+            config.session()?,
+        ))
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day)
+    }
+
+    /// Puzzle input of the day. Fails on any status except `200`, which usually means
+    /// that the session cookie has expired or the day is not opened yet.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let mut response = self
+            .agent
+            .get(format!("{}/input", self.day_url(day)))
+            .header("Cookie", format!("session={}", self.session))
+            .call()
+            .map_err(|err| format!("Cannot download input for day {}: {}", day, err))?;
+        let status = response.status();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| format!("Cannot read input for day {}: {}", day, err))?;
+
+        if status != 200 {
+            return Err(format!(
+                "Cannot download input for day {}, server responded with {}: {}\nUpdate session cookie in {} or {}",
+                day,
+                status,
+                body.lines().next().unwrap_or_default(),
+                SESSION_VAR,
+                CONFIG_FILE
+            ));
+        }
+        if body.trim().is_empty() {
+            return Err(format!("Server returned empty input for day {}", day));
+        }
+        Ok(body)
+    }
+
+    /// Posts the answer and returns the page with the verdict.
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<String, String> {
+        let level = part.to_string();
+        let mut response = self
+            .agent
+            .post(format!("{}/answer", self.day_url(day)))
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", level.as_str()), ("answer", answer.as_str())])
+            .map_err(|err| format!("Cannot submit answer for day {}: {}", day, err))?;
+        let status = response.status();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| format!("Cannot read verdict for day {}: {}", day, err))?;
+
+        if status != 200 {
+            return Err(format!(
+                "Cannot submit answer for day {}, server responded with {}\nUpdate session cookie in {} or {}",
+                day, status, SESSION_VAR, CONFIG_FILE
+            ));
+        }
+        Ok(body)
+    }
+}
@@ -0,0 +1,96 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Local configuration in the workspace root. It keeps the session cookie, so it is not committed.
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// Overrides the session cookie from the config.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_YEAR: u16 = 2022;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    session: Option<String>,
+    base_url: Option<String>,
+    year: Option<u16>,
+}
+
+impl Config {
+    pub fn load(workspace: &Path) -> Result<Self, String> {
+        let path = workspace.join(CONFIG_FILE);
+        let mut config = if path.is_file() {
+            let content = fs::read_to_string(&path)
+                .map_err(|err| format!("Cannot read '{}': {}", path.display(), err))?;
+            Self::parse(&content)
+                .map_err(|err| format!("Cannot parse '{}': {}", path.display(), err))?
+        } else {
+            Self::default()
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|err| err.to_string())
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| {
+                format!(
+                    "Session cookie is not set. Put it to {} or to 'session' in {}",
+                    SESSION_VAR, CONFIG_FILE
+                )
+            })
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or(DEFAULT_YEAR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::parse(
+            r#"
+session = "53616c7465645f5f"
+base_url = "http://localhost:8080"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.session(), Ok("53616c7465645f5f"));
+        assert_eq!(config.base_url(), "http://localhost:8080");
+        assert_eq!(config.year(), 2022);
+    }
+
+    #[test]
+    fn missing_session() {
+        let config = Config::parse("year = 2021").unwrap();
+        assert!(config.session().is_err());
+        assert_eq!(config.base_url(), "https://adventofcode.com");
+        assert_eq!(config.year(), 2021);
+    }
+
+    #[test]
+    fn unknown_field() {
+        assert!(Config::parse("cookie = \"abc\"").is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::client::Client;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Responses of the site are kept here, so inputs can be restored without downloading them again.
+pub const CACHE_DIR: &str = ".aoc/cache";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Writes the input of the day to `day_dir/input.txt` taking it from the cache in `workspace`
+/// or downloading it. Existing inputs are never overwritten.
+pub fn fetch(
+    client: &Client,
+    workspace: &Path,
+    day_dir: &Path,
+    day: u8,
+) -> Result<Fetched, String> {
+    let input_path = day_dir.join("input.txt");
+    if input_path.exists() {
+        return Err(format!(
+            "Input for day {} already exists in '{}'",
+            day,
+            input_path.display()
+        ));
+    }
+
+    let cache_path = cache_path(workspace, client.year(), day);
+    let (input, fetched) = match fs::read_to_string(&cache_path) {
+        Ok(input) => (input, Fetched::Cached),
+        Err(_) => {
+            let input = client.input(day)?;
+            write(&cache_path, &input)?;
+            (input, Fetched::Downloaded)
+        }
+    };
+    write(&input_path, &input)?;
+    Ok(fetched)
+}
+
+fn cache_path(workspace: &Path, year: u16, day: u8) -> PathBuf {
+    workspace
+        .join(CACHE_DIR)
+        .join(year.to_string())
+        .join(format!("day{}.txt", day))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    path.parent()
+        .map(fs::create_dir_all)
+        .transpose()
+        .and_then(|_| fs::write(path, content))
+        .map_err(|err| format!("Cannot write '{}': {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::fetch::{cache_path, fetch, Fetched};
+    use crate::stub_server::StubServer;
+    use std::env::temp_dir;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+
+    fn workspace(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("aoc_fetch_{}_{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn download_input() {
+        let server = StubServer::start(vec![(200, "1000\n2000\n")]);
+        let workspace = workspace("download");
+        let day_dir = workspace.join("day3");
+
+        let client = Client::new(&server.url, 2022, "secret");
+        assert_eq!(
+            fetch(&client, &workspace, &day_dir, 3),
+            Ok(Fetched::Downloaded)
+        );
+        assert_eq!(
+            fs::read_to_string(day_dir.join("input.txt")).unwrap(),
+            "1000\n2000\n"
+        );
+        assert_eq!(
+            fs::read_to_string(cache_path(&workspace, 2022, 3)).unwrap(),
+            "1000\n2000\n"
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/3/input "));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn restore_from_cache() {
+        let server = StubServer::start(vec![]);
+        let workspace = workspace("cache");
+        let day_dir = workspace.join("day5");
+        let cache = cache_path(&workspace, 2022, 5);
+        fs::create_dir_all(cache.parent().unwrap()).unwrap();
+        fs::write(&cache, "cached").unwrap();
+
+        let client = Client::new(&server.url, 2022, "secret");
+        assert_eq!(fetch(&client, &workspace, &day_dir, 5), Ok(Fetched::Cached));
+        assert_eq!(
+            fs::read_to_string(day_dir.join("input.txt")).unwrap(),
+            "cached"
+        );
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn refuse_existing_input() {
+        let server = StubServer::start(vec![]);
+        let workspace = workspace("existing");
+        let day_dir = workspace.join("day1");
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(day_dir.join("input.txt"), "mine").unwrap();
+
+        let client = Client::new(&server.url, 2022, "secret");
+        assert!(fetch(&client, &workspace, &day_dir, 1).is_err());
+        assert_eq!(
+            fs::read_to_string(day_dir.join("input.txt")).unwrap(),
+            "mine"
+        );
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn reject_failed_response() {
+        let server = StubServer::start(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        )]);
+        let workspace = workspace("failed");
+        let day_dir = workspace.join("day2");
+
+        let client = Client::new(&server.url, 2022, "expired");
+        let error = fetch(&client, &workspace, &day_dir, 2).unwrap_err();
+        assert!(error.contains("400"), "{}", error);
+        assert!(error.contains("Update session cookie"), "{}", error);
+        assert!(!day_dir.join("input.txt").exists());
+        assert!(!cache_path(&workspace, 2022, 2).exists());
+    }
+}
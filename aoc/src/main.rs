@@ -0,0 +1,399 @@
+use crate::answers::{Answers, Comparison, ANSWERS_FILE};
+use crate::client::Client;
+use crate::config::Config;
+use crate::fetch::Fetched;
+use crate::selection::DaySelection;
+use crate::submit::Verdict;
+use advent_util::bench::Bench;
+use advent_util::examples;
+use advent_util::input::{find_day_dir, workspace_dir, InputSource};
+use advent_util::output::{print_answers, OutputFormat};
+use advent_util::solution::{print_answer, Answer, DynSolution, Part, Timed};
+use clap::{Parser, Subcommand};
+use std::process::exit;
+
+mod answers;
+mod client;
+mod config;
+mod days;
+mod fetch;
+mod scaffold;
+mod selection;
+#[cfg(test)]
+mod stub_server;
+mod submit;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Runs solutions of the selected days on their inputs. Answers are recorded
+    /// in `.aoc/answers.toml` with hashes of the inputs, and a warning is printed
+    /// if an answer to the same input changes
+    Run {
+        /// Day (`7`), inclusive range of days (`3-9`) or `all`
+        #[arg(default_value = "all")]
+        days: DaySelection,
+        /// Runs only the given part (1 or 2)
+        #[arg(long, short)]
+        part: Option<Part>,
+        /// Reads the input of a single selected day from the file (`-` for stdin)
+        /// instead of `dayN/input.txt`
+        #[arg(long, short)]
+        input: Option<InputSource>,
+        /// `text` or `json` with one `{day, part, answer, elapsed}` record per line
+        #[arg(long, default_value = "text")]
+        format: OutputFormat,
+    },
+    /// Checks solutions of the selected days against answers recorded in their examples
+    /// and next to their inputs
+    Check {
+        /// Day (`7`), inclusive range of days (`3-9`) or `all`
+        #[arg(default_value = "all")]
+        days: DaySelection,
+    },
+    /// Runs solutions of the selected days on their inputs again and compares answers with
+    /// the ones recorded in `.aoc/answers.toml`
+    Verify {
+        /// Day (`7`), inclusive range of days (`3-9`) or `all`
+        #[arg(default_value = "all")]
+        days: DaySelection,
+        /// Replaces the recorded answers, which changed, with the new ones
+        #[arg(long)]
+        update: bool,
+    },
+    /// Measures parsing and every part of the selected days over repeated runs and prints
+    /// their mean, min and max times as a table
+    Bench {
+        /// Day (`7`), inclusive range of days (`3-9`) or `all`
+        #[arg(default_value = "all")]
+        days: DaySelection,
+        /// Measures only the given part (1 or 2)
+        #[arg(long, short)]
+        part: Option<Part>,
+        /// Number of runs of every day
+        #[arg(long, short = 'n', default_value_t = 10)]
+        runs: usize,
+    },
+    /// Downloads the input of the day to `dayN/input.txt` using the session cookie
+    /// from `AOC_SESSION` or `aoc.toml` in the workspace root
+    Fetch { day: u8 },
+    /// Creates the `dayN` crate with a stub solution and empty example fixtures and registers it
+    /// in the workspace, in the runner and in the input generators
+    New { day: u8 },
+    /// Solves the part of the day on its input and submits the answer. Verdicts are recorded
+    /// in `.aoc/attempts.toml`, so answers known to be wrong are never submitted again
+    Submit {
+        day: u8,
+        /// Part to submit (1 or 2)
+        part: Part,
+        /// Submits the given answer instead of solving the part, e.g. the letters read from
+        /// a multiline answer
+        #[arg(long, short)]
+        answer: Option<String>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Commands::Run {
+            days,
+            part,
+            input,
+            format,
+        } => run(&days, part, input, format),
+        Commands::Check { days } => check(&days),
+        Commands::Verify { days, update } => verify(&days, update),
+        Commands::Bench { days, part, runs } => bench(&days, part, runs),
+        Commands::Fetch { day } => fetch(day),
+        Commands::New { day } => new(day),
+        Commands::Submit { day, part, answer } => submit(day, part, answer),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}
+
+fn selected_solutions(days: &DaySelection) -> Result<Vec<Box<dyn DynSolution>>, String> {
+    let solutions = days::solutions()
+        .into_iter()
+        .filter(|solution| days.contains(solution.day()))
+        .collect::<Vec<_>>();
+    if solutions.is_empty() {
+        Err(format!("There are no solutions for days: {}", days))
+    } else {
+        Ok(solutions)
+    }
+}
+
+fn selected_parts(part: Option<Part>) -> Vec<Part> {
+    part.map(|part| vec![part])
+        .unwrap_or_else(|| Part::ALL.to_vec())
+}
+
+fn run(
+    days: &DaySelection,
+    part: Option<Part>,
+    input: Option<InputSource>,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let parts = selected_parts(part);
+    let solutions = selected_solutions(days)?;
+    if input.is_some() && solutions.len() > 1 {
+        return Err("Input can be given only for a single day".to_string());
+    }
+
+    let answers_path = workspace_dir().join(ANSWERS_FILE);
+    let mut answers = Answers::load(&answers_path)?;
+    let mut recorded = false;
+    for solution in solutions {
+        let input = input
+            .clone()
+            .unwrap_or(InputSource::Day(solution.day()))
+            .read()
+            .map_err(|err| err.to_string())?;
+        let timed = solution
+            .run_timed(&input, &parts)
+            .map_err(|err| format!("Cannot solve day {}: {}", solution.day(), err))?;
+        print_answers(solution.day(), &parts, &timed, format);
+
+        let hash = answers::input_hash(&input);
+        for (part, (answer, _)) in parts.iter().zip(&timed.answers) {
+            match answers.compare(solution.day(), *part, &hash, answer) {
+                Comparison::New => {
+                    answers.record(solution.day(), *part, &hash, answer);
+                    recorded = true;
+                }
+                Comparison::Same => {}
+                // the stored answer is kept, so a broken solution does not overwrite it
+                Comparison::Changed { stored } => eprintln!(
+                    "Warning: day {} part {} changed from '{}' to '{}' for the same input",
+                    solution.day(),
+                    part,
+                    stored,
+                    answer
+                ),
+            }
+        }
+    }
+
+    if recorded {
+        answers.save(&answers_path)?;
+    }
+    Ok(())
+}
+
+fn verify(days: &DaySelection, update: bool) -> Result<(), String> {
+    let answers_path = workspace_dir().join(ANSWERS_FILE);
+    let mut answers = Answers::load(&answers_path)?;
+    let mut changed = 0;
+    let mut recorded = false;
+    for solution in selected_solutions(days)? {
+        // days without inputs are skipped, so all days can be verified
+        let input = match InputSource::Day(solution.day()).read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Skipping day {}: {}", solution.day(), err);
+                continue;
+            }
+        };
+        let Timed {
+            answers: solved, ..
+        } = solution
+            .run_timed(&input, &Part::ALL)
+            .map_err(|err| format!("Cannot solve day {}: {}", solution.day(), err))?;
+
+        let hash = answers::input_hash(&input);
+        for (part, (answer, _)) in Part::ALL.iter().zip(&solved) {
+            match answers.compare(solution.day(), *part, &hash, answer) {
+                Comparison::Same => {
+                    println!("Day {} part {}: ok", solution.day(), part);
+                    continue;
+                }
+                Comparison::New => println!(
+                    "Day {} part {}: recorded '{}'",
+                    solution.day(),
+                    part,
+                    answer
+                ),
+                Comparison::Changed { stored } if update => println!(
+                    "Day {} part {}: updated from '{}' to '{}'",
+                    solution.day(),
+                    part,
+                    stored,
+                    answer
+                ),
+                Comparison::Changed { stored } => {
+                    changed += 1;
+                    println!(
+                        "Day {} part {}: expected '{}', but was '{}'",
+                        solution.day(),
+                        part,
+                        stored,
+                        answer
+                    );
+                    continue;
+                }
+            }
+            answers.record(solution.day(), *part, &hash, answer);
+            recorded = true;
+        }
+    }
+
+    if recorded {
+        answers.save(&answers_path)?;
+    }
+    if changed == 0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} answers changed, rerun with '--update' if they are right",
+            changed
+        ))
+    }
+}
+
+fn check(days: &DaySelection) -> Result<(), String> {
+    let mut failed = 0;
+    for solution in selected_solutions(days)? {
+        let day_dir = find_day_dir(solution.day())
+            .ok_or_else(|| format!("Cannot find directory of day {}", solution.day()))?;
+        for result in examples::check(solution.as_ref(), &day_dir) {
+            if !result.passed() {
+                failed += 1;
+            }
+            println!("Day {} {}", solution.day(), result);
+        }
+    }
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{} checks failed", failed))
+    }
+}
+
+fn bench(days: &DaySelection, part: Option<Part>, runs: usize) -> Result<(), String> {
+    let parts = selected_parts(part);
+    let mut benches = Vec::new();
+    for solution in selected_solutions(days)? {
+        // days without inputs are skipped, so the table can be built for all days
+        match InputSource::Day(solution.day()).read() {
+            Ok(input) => benches.push(
+                advent_util::bench::bench(solution.as_ref(), &input, &parts, runs)
+                    .map_err(|err| format!("Cannot solve day {}: {}", solution.day(), err))?,
+            ),
+            Err(err) => eprintln!("Skipping day {}: {}", solution.day(), err),
+        }
+    }
+
+    print_bench_table(&benches, &parts);
+    Ok(())
+}
+
+fn print_bench_table(benches: &[Bench], parts: &[Part]) {
+    let header = ["Day".to_string(), "Runs".to_string(), "Parse".to_string()]
+        .into_iter()
+        .chain(parts.iter().map(|part| format!("Part {}", part)))
+        .collect::<Vec<_>>();
+    let rows = benches
+        .iter()
+        .map(|bench| {
+            [
+                bench.day.to_string(),
+                bench.runs.to_string(),
+                bench.parse.to_string(),
+            ]
+            .into_iter()
+            .chain(bench.parts.iter().map(|(_, stats)| stats.to_string()))
+            .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([title.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    for row in [header].iter().chain(&rows) {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>();
+        println!("{}", cells.join(" | ").trim_end());
+    }
+}
+
+fn fetch(day: u8) -> Result<(), String> {
+    let workspace = workspace_dir();
+    let client = Client::from_config(&Config::load(&workspace)?)?;
+    let day_dir = find_day_dir(day)
+        .ok_or_else(|| format!("Cannot find day{0}, create it with 'aoc new {0}'", day))?;
+    let input_path = day_dir.join("input.txt");
+    match fetch::fetch(&client, &workspace, &day_dir, day)? {
+        Fetched::Downloaded => println!("Downloaded input to '{}'", input_path.display()),
+        Fetched::Cached => println!("Restored input to '{}' from cache", input_path.display()),
+    }
+    Ok(())
+}
+
+fn new(day: u8) -> Result<(), String> {
+    let workspace = workspace_dir();
+    for path in scaffold::scaffold(&workspace, day)? {
+        println!("Written '{}'", path.display());
+    }
+    println!(
+        "Put the example to day{0}/examples/example and fetch the input with 'aoc fetch {0}'",
+        day
+    );
+    Ok(())
+}
+
+fn solve(day: u8, part: Part) -> Result<Answer, String> {
+    let solution = selected_solutions(&DaySelection::Days(day..=day))?.remove(0);
+    let input = InputSource::Day(day)
+        .read()
+        .map_err(|err| err.to_string())?;
+    solution
+        .solve(&input, part)
+        .map_err(|err| format!("Cannot solve day {}: {}", day, err))
+}
+
+fn submit(day: u8, part: Part, answer: Option<String>) -> Result<(), String> {
+    let workspace = workspace_dir();
+    let client = Client::from_config(&Config::load(&workspace)?)?;
+    let answer = match answer {
+        Some(answer) => Answer::from(answer.trim()),
+        None => solve(day, part)?,
+    };
+    print_answer(day, part, &answer);
+
+    match submit::submit(
+        &client,
+        &workspace.join(submit::ATTEMPTS_FILE),
+        day,
+        part,
+        &answer,
+    )? {
+        Verdict::Correct => {
+            println!("Verdict: {}", Verdict::Correct);
+            Ok(())
+        }
+        verdict => Err(format!("Verdict: {}", verdict)),
+    }
+}
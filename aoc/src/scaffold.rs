@@ -0,0 +1,266 @@
+use advent_util::examples::EXAMPLES_DIR;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const BENCH_TEMPLATE: &str = include_str!("../templates/bench.rs.tmpl");
+const GENERATE_TEMPLATE: &str = include_str!("../templates/generate.rs.tmpl");
+
+/// Creates the `dayN` crate with a stub solution and empty example fixtures, then registers it
+/// in the workspace members, in the runner and in the input generators together with a stub
+/// generator. Returns created and changed files.
+pub fn scaffold(workspace: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = workspace.join(format!("day{}", day));
+    if crate_dir.exists() {
+        return Err(format!("'{}' already exists", crate_dir.display()));
+    }
+
+    // registration is prepared before writing anything, so a failure leaves the workspace intact
+    let aoc = workspace.join("aoc");
+    let aoc_gen = workspace.join("aoc_gen");
+    let registrations = [
+        (
+            workspace.join("Cargo.toml"),
+            vec![("\"day", format!("    \"day{}\",", day))],
+        ),
+        (
+            aoc.join("Cargo.toml"),
+            vec![("day", format!("day{0} = {{ path = \"../day{0}\" }}", day))],
+        ),
+        (
+            aoc.join("src").join("days.rs"),
+            vec![(
+                "Box::new(day",
+                format!("        Box::new(day{0}::Day{0}),", day),
+            )],
+        ),
+        (
+            aoc_gen.join("Cargo.toml"),
+            vec![("day", format!("day{0} = {{ path = \"../day{0}\" }}", day))],
+        ),
+        (
+            aoc_gen.join("src").join("lib.rs"),
+            vec![
+                ("pub mod day", format!("pub mod day{};", day)),
+                ("", format!("        {0} => day{0}::generate,", day)),
+                (
+                    "Box::new(day",
+                    format!("            Box::new(day{0}::Day{0}),", day),
+                ),
+            ],
+        ),
+    ]
+    .into_iter()
+    .map(|(path, lines)| {
+        let content = fs::read_to_string(&path)
+            .map_err(|err| format!("Cannot read '{}': {}", path.display(), err))?;
+        let content = lines
+            .iter()
+            .try_fold(content, |content, (prefix, line)| {
+                insert_day_line(&content, day, prefix, line)
+            })
+            .map_err(|err| format!("Cannot register day in '{}': {}", path.display(), err))?;
+        Ok((path, content))
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+
+    let example_dir = crate_dir.join(EXAMPLES_DIR).join("example");
+    let files = [
+        (crate_dir.join("Cargo.toml"), fill(CARGO_TEMPLATE, day)),
+        (
+            crate_dir.join("src").join("lib.rs"),
+            fill(LIB_TEMPLATE, day),
+        ),
+        (
+            crate_dir.join("src").join("main.rs"),
+            fill(MAIN_TEMPLATE, day),
+        ),
+        (
+            crate_dir.join("benches").join(format!("day{}.rs", day)),
+            fill(BENCH_TEMPLATE, day),
+        ),
+        (
+            aoc_gen.join("src").join(format!("day{}.rs", day)),
+            fill(GENERATE_TEMPLATE, day),
+        ),
+        (example_dir.join("input.txt"), String::new()),
+        (example_dir.join("part1.txt"), String::new()),
+        (example_dir.join("part2.txt"), String::new()),
+    ];
+
+    let mut written = Vec::new();
+    for (path, content) in files.into_iter().chain(registrations) {
+        path.parent()
+            .map(fs::create_dir_all)
+            .transpose()
+            .and_then(|_| fs::write(&path, content))
+            .map_err(|err| format!("Cannot write '{}': {}", path.display(), err))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+/// Day mentioned at the start of the trimmed line right after the prefix, e.g. 7 in `day7 = ...`
+/// or in `7 => ...` without the prefix.
+fn mentioned_day(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .map(|end| &rest[..end])
+        .unwrap_or(rest);
+    digits.parse().ok()
+}
+
+/// Inserts the line into the block of lines mentioning days, so the days stay ordered.
+fn insert_day_line(content: &str, day: u8, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, mentioned_day(line, prefix)?)))
+        .collect::<Vec<_>>();
+
+    if days.iter().any(|(_, mentioned)| *mentioned == day) {
+        return Err(format!("day {} is already registered", day));
+    }
+    let position = days
+        .iter()
+        .rev()
+        .find(|(_, mentioned)| *mentioned < day)
+        .map(|(i, _)| i + 1)
+        .or_else(|| days.first().map(|(i, _)| *i))
+        .ok_or_else(|| "there are no other days".to_string())?;
+
+    lines.insert(position, line);
+    let mut inserted = lines.join("\n");
+    if content.ends_with('\n') {
+        inserted.push('\n');
+    }
+    Ok(inserted)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{insert_day_line, scaffold};
+    use advent_util::examples::assert_fixtures;
+    use std::env::temp_dir;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+
+    fn workspace(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("aoc_scaffold_{}_{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("aoc").join("src")).unwrap();
+        fs::create_dir_all(dir.join("aoc_gen").join("src")).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day3\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\nday1 = { path = \"../day1\" }\nday3 = { path = \"../day3\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("aoc").join("src").join("days.rs"),
+            "    vec![\n        Box::new(day1::Day1),\n        Box::new(day3::Day3),\n    ]\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("aoc_gen").join("Cargo.toml"),
+            "[dev-dependencies]\nday1 = { path = \"../day1\" }\nday3 = { path = \"../day3\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("aoc_gen").join("src").join("lib.rs"),
+            "pub mod day1;\npub mod day3;\n\nmatch day {\n        1 => day1::generate,\n        \
+             3 => day3::generate,\n        _ => return None,\n}\n\nvec![\n            \
+             Box::new(day1::Day1),\n            Box::new(day3::Day3),\n]\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn insert_ordered() {
+        let content = "a\nday2 = 1\nday10 = 1\nb\n";
+        assert_eq!(
+            insert_day_line(content, 3, "day", "day3 = 1"),
+            Ok("a\nday2 = 1\nday3 = 1\nday10 = 1\nb\n".to_string())
+        );
+        assert_eq!(
+            insert_day_line(content, 1, "day", "day1 = 1"),
+            Ok("a\nday1 = 1\nday2 = 1\nday10 = 1\nb\n".to_string())
+        );
+        assert_eq!(
+            insert_day_line(content, 15, "day", "day15 = 1"),
+            Ok("a\nday2 = 1\nday10 = 1\nday15 = 1\nb\n".to_string())
+        );
+        assert!(insert_day_line(content, 10, "day", "day10 = 1").is_err());
+        assert!(insert_day_line("a\n", 1, "day", "day1 = 1").is_err());
+        assert_eq!(
+            insert_day_line("day1\nb", 2, "day", "day2"),
+            Ok("day1\nday2\nb".to_string())
+        );
+    }
+
+    #[test]
+    fn scaffold_day() {
+        let workspace = workspace("day");
+        scaffold(&workspace, 2).unwrap();
+
+        let lib = fs::read_to_string(workspace.join("day2/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day2;"));
+        assert!(lib.contains("impl Solution for Day2 {"));
+        assert!(!lib.contains("{{day}}"));
+        assert!(workspace.join("day2/benches/day2.rs").is_file());
+        assert_eq!(
+            fs::read_to_string(workspace.join("day2/examples/example/part1.txt")).unwrap(),
+            ""
+        );
+        // the generated examples test passes until the answers are filled in
+        assert_fixtures(&day2::Day2, workspace.join("day2").to_str().unwrap());
+
+        assert!(fs::read_to_string(workspace.join("Cargo.toml"))
+            .unwrap()
+            .contains("    \"day1\",\n    \"day2\",\n    \"day3\",\n"));
+        assert!(fs::read_to_string(workspace.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains("day2 = { path = \"../day2\" }\nday3"));
+        assert!(fs::read_to_string(workspace.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("Box::new(day2::Day2),\n        Box::new(day3::Day3),"));
+
+        let generators = fs::read_to_string(workspace.join("aoc_gen/src/lib.rs")).unwrap();
+        assert!(generators.contains("pub mod day1;\npub mod day2;\npub mod day3;\n"));
+        assert!(generators.contains("1 => day1::generate,\n        2 => day2::generate,\n"));
+        assert!(generators.contains("Box::new(day2::Day2),\n            Box::new(day3::Day3),"));
+        assert!(fs::read_to_string(workspace.join("aoc_gen/Cargo.toml"))
+            .unwrap()
+            .contains("day2 = { path = \"../day2\" }\nday3"));
+        let generator = fs::read_to_string(workspace.join("aoc_gen/src/day2.rs")).unwrap();
+        assert!(generator.contains("pub fn generate(rng: &mut StdRng, size: usize) -> String {"));
+
+        assert!(scaffold(&workspace, 2).is_err());
+    }
+
+    #[test]
+    fn keep_workspace_when_registration_fails() {
+        let workspace = workspace("registered");
+        fs::write(
+            workspace.join("aoc/src/days.rs"),
+            "Box::new(day4::Day4),\nBox::new(day5::Day5),\n",
+        )
+        .unwrap();
+        assert!(scaffold(&workspace, 5).is_err());
+        assert!(!workspace.join("day5").exists());
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Days chosen on the command line: a single day (`7`), an inclusive range (`3-9`) or `all`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Days(RangeInclusive<u8>),
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Days(days) => days.contains(&day),
+        }
+    }
+}
+
+impl Display for DaySelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySelection::All => write!(f, "all"),
+            DaySelection::Days(days) if days.start() == days.end() => write!(f, "{}", days.start()),
+            DaySelection::Days(days) => write!(f, "{}-{}", days.start(), days.end()),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .map_err(|err| format!("Cannot parse day '{}': {}", day, err))
+        };
+
+        if s.trim() == "all" {
+            Ok(DaySelection::All)
+        } else if let Some((from, to)) = s.split_once('-') {
+            let from = parse_day(from)?;
+            let to = parse_day(to)?;
+            if from > to {
+                Err(format!("Empty range of days: {}", s))
+            } else {
+                Ok(DaySelection::Days(from..=to))
+            }
+        } else {
+            parse_day(s).map(|day| DaySelection::Days(day..=day))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::selection::DaySelection;
+
+    #[test]
+    fn parse_single_day() {
+        assert_eq!("7".parse(), Ok(DaySelection::Days(7..=7)));
+    }
+
+    #[test]
+    fn parse_range() {
+        let selection: DaySelection = "3-9".parse().unwrap();
+        assert_eq!(selection, DaySelection::Days(3..=9));
+        assert!(selection.contains(3));
+        assert!(selection.contains(9));
+        assert!(!selection.contains(10));
+    }
+
+    #[test]
+    fn parse_all() {
+        assert_eq!("all".parse(), Ok(DaySelection::All));
+    }
+
+    #[test]
+    fn parse_wrong_range() {
+        assert!("9-3".parse::<DaySelection>().is_err());
+        assert!("x".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,362 @@
+use crate::client::Client;
+use advent_util::solution::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+/// Answers submitted before, relative to the workspace root.
+pub const ATTEMPTS_FILE: &str = ".aoc/attempts.toml";
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong answer without a hint, which is given only for some wrong numbers.
+    Wrong,
+    /// The answer was not checked, because the previous one was submitted too recently.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part is already solved or is not opened yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned after submitting an answer.
+    pub fn parse(page: &str) -> Result<Self, String> {
+        let message = article(page);
+        if message.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if message.contains("You gave an answer too recently") {
+            let wait = message
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Ok(Verdict::RateLimited { wait })
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Ok(Verdict::TooHigh)
+            } else if message.contains("too low") {
+                Ok(Verdict::TooLow)
+            } else {
+                Ok(Verdict::Wrong)
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            Err(format!("Unknown verdict: {}", message))
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, {} left to wait", wait)
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "already solved or not opened yet"),
+        }
+    }
+}
+
+/// Text of the `<article>` with the verdict without tags.
+fn article(page: &str) -> String {
+    // the rest of the opening tag is left after the split, so the text starts inside of a tag
+    let (article, mut in_tag) = match page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        Some((article, _)) => (article, true),
+        None => (page, false),
+    };
+
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Attempts {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| toml::from_str(&content).map_err(|err| err.to_string()))
+            .map_err(|err| format!("Cannot read attempts from '{}': {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|err| err.to_string())?;
+        path.parent()
+            .map(fs::create_dir_all)
+            .transpose()
+            .and_then(|_| fs::write(path, content))
+            .map_err(|err| format!("Cannot write attempts to '{}': {}", path.display(), err))
+    }
+
+    fn of(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |attempt| {
+            attempt.year == year && attempt.day == day && attempt.part == part.number()
+        })
+    }
+
+    /// Explains why the answer must not be submitted: the part is solved, the same answer
+    /// was already wrong, or the answer is out of the bounds given by previous hints.
+    pub fn rejection(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Option<String> {
+        let number = answer.as_str().parse::<i64>().ok();
+        self.of(year, day, part).find_map(|attempt| {
+            let previous = attempt.answer.parse::<i64>().ok();
+            match (&attempt.verdict, number, previous) {
+                (Verdict::Correct, _, _) => Some(format!(
+                    "Day {} part {} is already solved with '{}'",
+                    day, part, attempt.answer
+                )),
+                (verdict, _, _) if attempt.answer == answer.as_str() => Some(format!(
+                    "'{}' was already submitted for day {} part {}: {}",
+                    answer, day, part, verdict
+                )),
+                (Verdict::TooHigh, Some(number), Some(previous)) if number >= previous => {
+                    Some(format!(
+                        "'{}' is not lower than '{}', which is too high",
+                        answer, attempt.answer
+                    ))
+                }
+                (Verdict::TooLow, Some(number), Some(previous)) if number <= previous => {
+                    Some(format!(
+                        "'{}' is not higher than '{}', which is too low",
+                        answer, attempt.answer
+                    ))
+                }
+                _ => None,
+            }
+        })
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+}
+
+/// Submits the answer unless previous attempts show that it is wrong and records the verdict.
+/// Answers of several lines, like the CRT image of day 10, are never submitted, because
+/// the site expects the letters they show.
+/// Rate limited and wrong level attempts are not recorded, because the answer was not checked.
+pub fn submit(
+    client: &Client,
+    attempts_path: &Path,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Verdict, String> {
+    if answer.as_str().contains('\n') {
+        return Err(format!(
+            "The answer of day {} part {} has several lines, read it and submit it with --answer",
+            day, part
+        ));
+    }
+    let mut attempts = Attempts::load(attempts_path)?;
+    if let Some(rejection) = attempts.rejection(client.year(), day, part, answer) {
+        return Err(rejection);
+    }
+
+    let verdict = Verdict::parse(&client.submit(day, part, answer)?)?;
+    if !matches!(verdict, Verdict::RateLimited { .. } | Verdict::WrongLevel) {
+        attempts.record(Attempt {
+            year: client.year(),
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            verdict: verdict.clone(),
+        });
+        attempts.save(attempts_path)?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::stub_server::StubServer;
+    use crate::submit::{submit, Attempt, Attempts, Verdict};
+    use advent_util::solution::{Answer, Part};
+    use std::env::temp_dir;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+
+    const CORRECT: &str = r#"<main>
+<article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to collecting enough star fruit. <a href="/2022/day/1#part2">[Continue to Part Two]</a></p></article>
+</main>"#;
+    const TOO_HIGH: &str = r#"<main>
+<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2022/about">about page</a>. Please wait one minute before trying again. <a href="/2022/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+    const TOO_RECENT: &str = r#"<main>
+<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait. <a href="/2022/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+
+    const WRONG_LEVEL: &str = r#"<main>
+<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href="/2022/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+
+    fn attempts_path(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("aoc_submit_{}_{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("attempts.toml")
+    }
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            year: 2022,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn parse_verdict() {
+        assert_eq!(Verdict::parse(CORRECT), Ok(Verdict::Correct));
+        assert_eq!(Verdict::parse(TOO_HIGH), Ok(Verdict::TooHigh));
+        assert_eq!(
+            Verdict::parse(&TOO_HIGH.replace("too high", "too low")),
+            Ok(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::parse(TOO_RECENT),
+            Ok(Verdict::RateLimited {
+                wait: Some("37s".to_string())
+            })
+        );
+        assert!(Verdict::parse("<article><p>Something else</p></article>").is_err());
+    }
+
+    #[test]
+    fn reject_known_wrong_answers() {
+        let mut attempts = Attempts::default();
+        attempts.record(attempt("100", Verdict::TooHigh));
+        attempts.record(attempt("10", Verdict::TooLow));
+        attempts.record(attempt("abc", Verdict::Wrong));
+
+        let rejected = |attempts: &Attempts, answer| {
+            attempts
+                .rejection(2022, 1, Part::One, &Answer::from(answer))
+                .is_some()
+        };
+        assert!(rejected(&attempts, "100"));
+        assert!(rejected(&attempts, "150"));
+        assert!(rejected(&attempts, "5"));
+        assert!(rejected(&attempts, "abc"));
+        assert!(!rejected(&attempts, "50"));
+        assert!(attempts
+            .rejection(2022, 1, Part::Two, &Answer::from("100"))
+            .is_none());
+
+        attempts.record(attempt("50", Verdict::Correct));
+        assert!(rejected(&attempts, "60"));
+    }
+
+    #[test]
+    fn never_resubmit_wrong_answer() {
+        let server = StubServer::start(vec![(200, TOO_HIGH)]);
+        let path = attempts_path("wrong");
+        let client = Client::new(&server.url, 2022, "secret");
+
+        assert_eq!(
+            submit(&client, &path, 1, Part::One, &Answer::new(75000)),
+            Ok(Verdict::TooHigh)
+        );
+        assert!(submit(&client, &path, 1, Part::One, &Answer::new(75000)).is_err());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /2022/day/1/answer "));
+        assert!(requests[0].ends_with("level=1&answer=75000"));
+        assert_eq!(
+            Attempts::load(&path).unwrap().attempts,
+            vec![attempt("75000", Verdict::TooHigh)]
+        );
+    }
+
+    #[test]
+    fn rate_limited_answer_is_not_recorded() {
+        let server = StubServer::start(vec![(200, TOO_RECENT), (200, CORRECT)]);
+        let path = attempts_path("rate_limited");
+        let client = Client::new(&server.url, 2022, "secret");
+
+        assert!(matches!(
+            submit(&client, &path, 1, Part::One, &Answer::new(24000)),
+            Ok(Verdict::RateLimited { .. })
+        ));
+        assert!(Attempts::load(&path).unwrap().attempts.is_empty());
+        assert_eq!(
+            submit(&client, &path, 1, Part::One, &Answer::new(24000)),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn wrong_level_answer_is_not_recorded() {
+        let server = StubServer::start(vec![(200, WRONG_LEVEL), (200, CORRECT)]);
+        let path = attempts_path("wrong_level");
+        let client = Client::new(&server.url, 2022, "secret");
+
+        assert_eq!(
+            submit(&client, &path, 1, Part::Two, &Answer::new(45000)),
+            Ok(Verdict::WrongLevel)
+        );
+        assert!(Attempts::load(&path).unwrap().attempts.is_empty());
+        assert_eq!(
+            submit(&client, &path, 1, Part::Two, &Answer::new(45000)),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn multiline_answer_is_not_submitted() {
+        let server = StubServer::start(vec![(200, CORRECT)]);
+        let path = attempts_path("multiline");
+        let client = Client::new(&server.url, 2022, "secret");
+
+        let screen = Answer::from("##..\n#..#");
+        assert!(submit(&client, &path, 10, Part::Two, &screen)
+            .unwrap_err()
+            .contains("--answer"));
+        assert!(server.requests().is_empty());
+        assert!(Attempts::load(&path).unwrap().attempts.is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Food of `size` elves, at least three of them as the top three are summed.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let elves = (0..size.max(3))
+        .map(|_| {
+            let items = rng.random_range(1..=15);
+            (0..items)
+                .map(|_| rng.random_range(1000..=70000).to_string() + "\n")
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    elves.join("\n")
+}
@@ -0,0 +1,23 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Program running for at least `size` cycles, 240 of them fill the CRT.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut cycles = 0;
+    let mut register = 1;
+    let mut program = Vec::new();
+    while cycles < size.max(1) {
+        if rng.random_bool(0.3) {
+            program.push("noop".to_string());
+            cycles += 1;
+        } else {
+            // the sprite is kept around the screen, so the image is not empty
+            let value = rng.random_range(-(register + 5).min(20)..=(40 - register).min(20));
+            register += value;
+            program.push(format!("addx {}", value));
+            cycles += 2;
+        }
+    }
+    join_lines(program)
+}
@@ -0,0 +1,122 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Tests of monkeys are distinct primes like in the puzzle, so their product stays small.
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+const ROUNDS: usize = 20;
+
+enum Operation {
+    Square,
+    Multiply(u64),
+    Add(u64),
+}
+
+impl Operation {
+    fn apply(&self, old: u64) -> Option<u64> {
+        match self {
+            Operation::Square => old.checked_mul(old),
+            Operation::Multiply(value) => old.checked_mul(*value),
+            Operation::Add(value) => old.checked_add(*value),
+        }
+    }
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+/// Notes of `size` monkeys, from two to nine. Monkeys are generated again until worry levels
+/// of the first part fit into `u64`, which is not the case for every random game.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = size.clamp(2, PRIMES.len());
+    loop {
+        let monkeys = monkeys(rng, count);
+        if worry_fits(&monkeys) {
+            let notes = monkeys
+                .iter()
+                .enumerate()
+                .map(|(id, monkey)| notes(id, monkey))
+                .collect::<Vec<_>>();
+            return notes.join("\n");
+        }
+    }
+}
+
+fn monkeys(rng: &mut StdRng, count: usize) -> Vec<Monkey> {
+    let mut tests = PRIMES[..count].to_vec();
+    tests.shuffle(rng);
+    tests
+        .into_iter()
+        .enumerate()
+        .map(|(id, test)| {
+            let operation = match rng.random_range(0..10) {
+                0 => Operation::Square,
+                1..=3 => Operation::Multiply(rng.random_range(2..=19)),
+                _ => Operation::Add(rng.random_range(1..=8)),
+            };
+            let mut others = (0..count).filter(|&other| other != id).collect::<Vec<_>>();
+            others.shuffle(rng);
+            Monkey {
+                items: (0..rng.random_range(1..=6))
+                    .map(|_| rng.random_range(50..=99))
+                    .collect(),
+                operation,
+                test,
+                if_true: others[0],
+                if_false: *others.last().unwrap(),
+            }
+        })
+        .collect()
+}
+
+/// Plays the first part, where worry levels are divided by three instead of being wrapped.
+fn worry_fits(monkeys: &[Monkey]) -> bool {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<_>>();
+    for _ in 0..ROUNDS {
+        for (id, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[id]) {
+                let Some(worry) = monkey.operation.apply(item) else {
+                    return false;
+                };
+                let worry = worry / 3;
+                let target = if worry.is_multiple_of(monkey.test) {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+                items[target].push(worry);
+            }
+        }
+    }
+    true
+}
+
+fn notes(id: usize, monkey: &Monkey) -> String {
+    let items = monkey
+        .items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>();
+    let operation = match monkey.operation {
+        Operation::Square => "old * old".to_string(),
+        Operation::Multiply(value) => format!("old * {}", value),
+        Operation::Add(value) => format!("old + {}", value),
+    };
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        id,
+        items.join(", "),
+        operation,
+        monkey.test,
+        monkey.if_true,
+        monkey.if_false
+    )
+}
@@ -0,0 +1,65 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+const HIGHEST: u8 = b'z' - b'a';
+/// Sides are long enough to climb from `a` to `z` one step at a time.
+const MIN_SIDE: usize = 20;
+
+/// Heightmap `size` wide and half as high. Neighbouring squares differ by one at most,
+/// so the best signal is reachable from everywhere.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let columns = size.max(MIN_SIDE);
+    let rows = (size / 2).max(MIN_SIDE);
+    loop {
+        let mut map = heights(rng, rows, columns)
+            .into_iter()
+            .map(|row| row.into_iter().map(|height| b'a' + height).collect())
+            .collect::<Vec<Vec<_>>>();
+        let squares = |height| {
+            (0..rows)
+                .flat_map(|row| (0..columns).map(move |column| (row, column)))
+                .filter(|&(row, column)| map[row][column] == height)
+                .collect::<Vec<_>>()
+        };
+        // random maps do not always climb up to the top
+        let (Some(&start), Some(&end)) = (
+            squares(b'a').choose(rng),
+            squares(b'a' + HIGHEST).choose(rng),
+        ) else {
+            continue;
+        };
+        map[start.0][start.1] = b'S';
+        map[end.0][end.1] = b'E';
+        return join_lines(map.into_iter().map(|row| String::from_utf8(row).unwrap()));
+    }
+}
+
+/// Every square is chosen to be close to the ones above and on the left, mostly climbing up.
+fn heights(rng: &mut StdRng, rows: usize, columns: usize) -> Vec<Vec<u8>> {
+    let mut heights = vec![vec![0u8; columns]; rows];
+    for row in 0..rows {
+        for column in 0..columns {
+            let neighbours = [
+                row.checked_sub(1).map(|row| heights[row][column]),
+                column.checked_sub(1).map(|column| heights[row][column]),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+            let (Some(&lowest), Some(&highest)) =
+                (neighbours.iter().min(), neighbours.iter().max())
+            else {
+                continue;
+            };
+            let (min, max) = (highest.saturating_sub(1), (lowest + 1).min(HIGHEST));
+            heights[row][column] = if rng.random_bool(0.8) {
+                max
+            } else {
+                rng.random_range(min..=max)
+            };
+        }
+    }
+    heights
+}
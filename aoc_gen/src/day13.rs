@@ -0,0 +1,25 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const MAX_DEPTH: usize = 4;
+
+/// `size` pairs of packets.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let pairs = (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", list(rng, 0), list(rng, 0)))
+        .collect::<Vec<_>>();
+    pairs.join("\n")
+}
+
+fn list(rng: &mut StdRng, depth: usize) -> String {
+    let elements = (0..rng.random_range(0..=5))
+        .map(|_| {
+            if depth < MAX_DEPTH && rng.random_bool(0.3) {
+                list(rng, depth + 1)
+            } else {
+                rng.random_range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", elements.join(","))
+}
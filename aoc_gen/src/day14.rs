@@ -0,0 +1,88 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashSet;
+
+const SAND_SOURCE: (i32, i32) = (500, 0);
+
+/// `size` paths of rock, each with up to four straight lines. Paths are generated again
+/// if sand piles up to its source without the floor, as it must fall into the abyss.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // rocks of big caves are spread wider, but not so much that sand misses them
+    let spread = 10 + (16 * size).isqrt() as i32;
+    loop {
+        let paths = (0..size.max(1))
+            .map(|_| path(rng, spread))
+            .collect::<Vec<_>>();
+        if !blocks_source(&paths) {
+            return join_lines(paths.iter().map(|path| {
+                let points = path
+                    .iter()
+                    .map(|(x, y)| format!("{},{}", x, y))
+                    .collect::<Vec<_>>();
+                points.join(" -> ")
+            }));
+        }
+    }
+}
+
+fn path(rng: &mut StdRng, spread: i32) -> Vec<(i32, i32)> {
+    let mut point = (
+        SAND_SOURCE.0 + rng.random_range(-spread..=spread),
+        rng.random_range(2..=spread),
+    );
+    let mut horizontal = rng.random_bool(0.5);
+    let mut path = vec![point];
+    for _ in 0..rng.random_range(1..=4) {
+        let length = rng.random_range(1..=8) * if rng.random_bool(0.5) { 1 } else { -1 };
+        if horizontal {
+            point.0 += length;
+        } else {
+            // rocks stay below the source
+            point.1 = (point.1 + length).max(2);
+        }
+        if path.last() != Some(&point) {
+            path.push(point);
+        }
+        horizontal = !horizontal;
+    }
+    path
+}
+
+/// Pours sand until it falls below all rocks or comes to rest at the source.
+fn blocks_source(paths: &[Vec<(i32, i32)>]) -> bool {
+    let mut blocked = HashSet::new();
+    for path in paths {
+        for line in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (line[0], line[1]);
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    blocked.insert((x, y));
+                }
+            }
+        }
+        blocked.insert(path[0]);
+    }
+    let lowest = blocked.iter().map(|&(_, y)| y).max().unwrap_or_default();
+
+    loop {
+        let mut sand = SAND_SOURCE;
+        loop {
+            if sand.1 > lowest {
+                return false;
+            }
+            let next = [0, -1, 1]
+                .map(|dx| (sand.0 + dx, sand.1 + 1))
+                .into_iter()
+                .find(|next| !blocked.contains(next));
+            match next {
+                Some(next) => sand = next,
+                None if sand == SAND_SOURCE => return true,
+                None => {
+                    blocked.insert(sand);
+                    break;
+                }
+            }
+        }
+    }
+}
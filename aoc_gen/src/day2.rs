@@ -0,0 +1,12 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+
+/// Strategy guide of `size` rounds.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let opponent = ['A', 'B', 'C'].choose(rng).unwrap();
+        let response = ['X', 'Y', 'Z'].choose(rng).unwrap();
+        format!("{} {}", opponent, response)
+    }))
+}
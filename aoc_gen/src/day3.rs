@@ -0,0 +1,47 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+
+/// Rucksacks of `size` groups of three elves. Compartments of every rucksack share exactly
+/// one item type, and rucksacks of every group share exactly one badge.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let item_types = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    join_lines((0..size.max(1)).flat_map(|_| {
+        let mut item_types = item_types.clone();
+        item_types.shuffle(rng);
+        let badge = item_types.pop().unwrap();
+        // every elf takes its own item types, so nothing but the badge is shared by the group
+        item_types
+            .chunks_exact(item_types.len() / 3)
+            .map(|own| {
+                rucksack(
+                    rng,
+                    badge,
+                    own[0],
+                    &own[1..own.len() / 2],
+                    &own[own.len() / 2..],
+                )
+            })
+            .collect::<Vec<_>>()
+    }))
+}
+
+/// The badge is put only to the first compartment, so only `shared` is in both of them.
+fn rucksack(
+    rng: &mut StdRng,
+    badge: char,
+    shared: char,
+    first: &[char],
+    second: &[char],
+) -> String {
+    let length = rng.random_range(3..=16);
+    let mut compartments = [vec![shared, badge], vec![shared]];
+    for (compartment, items) in compartments.iter_mut().zip([first, second]) {
+        while compartment.len() < length {
+            compartment.push(*items.choose(rng).unwrap());
+        }
+        compartment.shuffle(rng);
+    }
+    compartments.concat().into_iter().collect()
+}
@@ -0,0 +1,14 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// `size` pairs of section assignments.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let [first, second] = [(); 2].map(|_| {
+            let start = rng.random_range(1..=99);
+            (start, rng.random_range(start..=99))
+        });
+        format!("{}-{},{}-{}", first.0, first.1, second.0, second.1)
+    }))
+}
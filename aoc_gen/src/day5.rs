@@ -0,0 +1,47 @@
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+/// Drawing of up to nine stacks and `size` moves, which never take more crates than
+/// the stack has.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let stack_count = rng.random_range(3..=9);
+    let stacks = (0..stack_count)
+        .map(|_| {
+            (0..rng.random_range(1..=8))
+                .map(|_| char::from(rng.random_range(b'A'..=b'Z')))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut input = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    for level in (0..height).rev() {
+        let crates = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(label) => format!("[{}]", label),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<_>>();
+        input += &(crates.join(" ") + "\n");
+    }
+    let titles = (1..=stack_count)
+        .map(|title| format!(" {} ", title))
+        .collect::<Vec<_>>();
+    input += &(titles.join(" ") + "\n\n");
+
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+    for _ in 0..size {
+        let non_empty = (0..stack_count)
+            .filter(|&stack| heights[stack] > 0)
+            .collect::<Vec<_>>();
+        let from = *non_empty.choose(rng).unwrap();
+        let to = (from + rng.random_range(1..stack_count)) % stack_count;
+        let count = rng.random_range(1..=heights[from]);
+        heights[from] -= count;
+        heights[to] += count;
+        input += &format!("move {} from {} to {}\n", count, from + 1, to + 1);
+    }
+    input
+}
@@ -0,0 +1,22 @@
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+
+const MESSAGE_MARKER: usize = 14;
+
+/// Datastream of about `size` characters with the start-of-message marker in the middle.
+/// Signals before the marker use fewer letters than it has, so it is not found earlier.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut letters = ('a'..='z').collect::<Vec<_>>();
+    letters.shuffle(rng);
+    let noise = &letters[..rng.random_range(2..MESSAGE_MARKER)];
+
+    let mut marker = letters.clone();
+    marker.shuffle(rng);
+    let mut datastream = (0..size / 2)
+        .map(|_| *noise.choose(rng).unwrap())
+        .collect::<String>();
+    datastream.extend(marker.into_iter().take(MESSAGE_MARKER));
+    datastream.extend((0..size / 2).map(|_| *letters.choose(rng).unwrap()));
+    datastream + "\n"
+}
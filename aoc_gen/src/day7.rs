@@ -0,0 +1,107 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+const MAX_FILES: usize = 4;
+/// Files take more than 40000000 of the disk of 70000000, so some of them must be deleted
+/// to free 30000000, but still fit.
+const USED_SPACE: RangeInclusive<u32> = 41_000_000..=69_000_000;
+
+struct Directory {
+    children: Vec<(String, usize)>,
+    files: Vec<(String, u32)>,
+}
+
+/// Terminal output of browsing a tree of `size` directories, each of them is listed once.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut directories: Vec<Directory> = Vec::new();
+    // names of files and directories in every directory
+    let mut names = Vec::new();
+    for id in 0..=size {
+        if id > 0 {
+            let parent = rng.random_range(0..id);
+            let name = unique_name(rng, &mut names[parent], "");
+            directories[parent].children.push((name, id));
+        }
+        names.push(HashSet::new());
+        let files = (0..rng.random_range(0..=MAX_FILES))
+            .map(|_| {
+                let extension = [".txt", ".dat", ".log", ""][rng.random_range(0..4)];
+                (
+                    unique_name(rng, &mut names[id], extension),
+                    rng.random_range(1..=1000),
+                )
+            })
+            .collect();
+        directories.push(Directory {
+            children: Vec::new(),
+            files,
+        });
+    }
+
+    if directories
+        .iter()
+        .all(|directory| directory.files.is_empty())
+    {
+        directories[0].files.push(("a.txt".to_string(), 1));
+    }
+    scale_file_sizes(&mut directories, rng.random_range(USED_SPACE));
+
+    let mut lines = vec!["$ cd /".to_string()];
+    browse(rng, &directories, 0, &mut lines);
+    join_lines(lines)
+}
+
+/// Sizes are generated as weights first, so the total can be chosen for any number of files.
+fn scale_file_sizes(directories: &mut [Directory], used_space: u32) {
+    let total = directories
+        .iter()
+        .flat_map(|directory| &directory.files)
+        .map(|(_, size)| *size as u64)
+        .sum::<u64>();
+    for (_, size) in directories
+        .iter_mut()
+        .flat_map(|directory| &mut directory.files)
+    {
+        *size = (*size as u64 * used_space as u64 / total).max(1) as u32;
+    }
+}
+
+fn unique_name(rng: &mut StdRng, taken: &mut HashSet<String>, extension: &str) -> String {
+    loop {
+        let name = (0..rng.random_range(1..=8))
+            .map(|_| char::from(rng.random_range(b'a'..=b'z')))
+            .collect::<String>()
+            + extension;
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn browse(rng: &mut StdRng, directories: &[Directory], id: usize, lines: &mut Vec<String>) {
+    let directory = &directories[id];
+    let mut listing = directory
+        .children
+        .iter()
+        .map(|(name, _)| format!("dir {}", name))
+        .chain(
+            directory
+                .files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect::<Vec<_>>();
+    listing.shuffle(rng);
+    lines.push("$ ls".to_string());
+    lines.append(&mut listing);
+
+    for (name, child) in &directory.children {
+        lines.push(format!("$ cd {}", name));
+        browse(rng, directories, *child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
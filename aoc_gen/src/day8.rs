@@ -0,0 +1,13 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Square forest of `size` by `size` trees.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    join_lines((0..size).map(|_| {
+        (0..size)
+            .map(|_| char::from(b'0' + rng.random_range(0..=9)))
+            .collect()
+    }))
+}
@@ -0,0 +1,12 @@
+use crate::join_lines;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+/// `size` moves of the head of the rope.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+        format!("{} {}", direction, rng.random_range(1..=20))
+    }))
+}
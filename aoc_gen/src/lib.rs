@@ -0,0 +1,111 @@
+//! Random inputs of every day, which are valid by the rules of the puzzles, so parsers can be
+//! stress tested and optimised solutions compared with naive ones on more than one input.
+
+use rand::rngs::StdRng;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Generates the input of the given size, which is the number of its main items,
+/// e.g. lines, monkeys or directories. See generators of days for the exact meaning.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day1::generate,
+        2 => day2::generate,
+        3 => day3::generate,
+        4 => day4::generate,
+        5 => day5::generate,
+        6 => day6::generate,
+        7 => day7::generate,
+        8 => day8::generate,
+        9 => day9::generate,
+        10 => day10::generate,
+        11 => day11::generate,
+        12 => day12::generate,
+        13 => day13::generate,
+        14 => day14::generate,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// Lines joined with line breaks and ended with one like downloaded inputs.
+fn join_lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator;
+    use advent_util::solution::{DynSolution, Part};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn solutions() -> Vec<Box<dyn DynSolution>> {
+        vec![
+            Box::new(day1::Day1),
+            Box::new(day2::Day2),
+            Box::new(day3::Day3),
+            Box::new(day4::Day4),
+            Box::new(day5::Day5),
+            Box::new(day6::Day6),
+            Box::new(day7::Day7),
+            Box::new(day8::Day8),
+            Box::new(day9::Day9),
+            Box::new(day10::Day10),
+            Box::new(day11::Day11),
+            Box::new(day12::Day12),
+            Box::new(day13::Day13),
+            Box::new(day14::Day14),
+        ]
+    }
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for solution in solutions() {
+            let generate = generator(solution.day()).unwrap();
+            for (seed, size) in [(1, 1), (2, 10), (3, 40)] {
+                let input = generate(&mut StdRng::seed_from_u64(seed), size);
+                if let Err(err) = solution.run(&input, &Part::ALL) {
+                    panic!("Day {} cannot solve\n{}\n{}", solution.day(), input, err);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for solution in solutions() {
+            let generate = generator(solution.day()).unwrap();
+            assert_eq!(
+                generate(&mut StdRng::seed_from_u64(7), 20),
+                generate(&mut StdRng::seed_from_u64(7), 20)
+            );
+        }
+    }
+
+    #[test]
+    fn generator_of_every_solved_day() {
+        let days = solutions()
+            .iter()
+            .map(|solution| solution.day())
+            .collect::<Vec<_>>();
+        for day in 0..=25 {
+            assert_eq!(generator(day).is_some(), days.contains(&day), "day {}", day);
+        }
+    }
+}
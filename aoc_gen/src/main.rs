@@ -0,0 +1,46 @@
+use aoc_gen::generator;
+use clap::Parser;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::fs;
+use std::path::PathBuf;
+use std::process::exit;
+
+#[derive(Parser)]
+#[command(
+    name = "aoc_gen",
+    about = "Generates random inputs of Advent of Code 2022 days"
+)]
+struct Cli {
+    day: u8,
+    /// Number of the main items of the input, e.g. lines, monkeys or directories
+    #[arg(long, short, default_value_t = 100)]
+    size: usize,
+    /// Seed of the random generator, the same seed gives the same input
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Writes the input to the file instead of stdout
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let Some(generate) = generator(cli.day) else {
+        eprintln!("There is no generator for day {}", cli.day);
+        exit(1);
+    };
+    let mut rng = match cli.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+
+    let input = generate(&mut rng, cli.size);
+    match cli.output {
+        Some(path) => fs::write(&path, input).unwrap_or_else(|err| {
+            eprintln!("Cannot write '{}': {}", path.display(), err);
+            exit(1);
+        }),
+        None => print!("{}", input),
+    }
+}
@@ -0,0 +1,211 @@
+//! Totals of calories read elf by elf, so snacks of huge inventories are never kept in memory.
+//!
+//! Only the top elves, the count and the sum of the totals are kept, unless the exact median is
+//! asked for with [CalorieAggregator::with_median], which keeps the total of every elf.
+
+use advent_util::parse::{number, ParseError};
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// Calories carried by the elf, which is counted from zero in the order of the inventory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// Reads blocks of snacks separated by empty lines and keeps the `k` elves carrying the most.
+/// Memory grows with `k`, and with the number of elves only if the median is enabled.
+pub struct CalorieAggregator {
+    k: usize,
+    /// The least of the top elves is on top, ties keep the earlier elf.
+    top: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+    count: usize,
+    total: u64,
+    /// Total of every elf when the median is enabled, `None` otherwise.
+    totals: Option<Vec<u64>>,
+    /// Calories of the elf being read, `None` between elves.
+    current: Option<u64>,
+    line: usize,
+}
+
+impl CalorieAggregator {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            top: BinaryHeap::with_capacity(k + 1),
+            count: 0,
+            total: 0,
+            totals: None,
+            current: None,
+            line: 0,
+        }
+    }
+
+    /// Keeps the total of every elf (8 bytes each) to compute the exact median.
+    pub fn with_median(mut self) -> Self {
+        self.totals = Some(Vec::new());
+        self
+    }
+
+    pub fn push_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.line += 1;
+        if line.trim().is_empty() {
+            self.finish_elf();
+            return Ok(());
+        }
+        let calories = number::<u64>(line).map_err(|err| err.at(self.line, 1))?;
+        *self.current.get_or_insert(0) += calories;
+        Ok(())
+    }
+
+    fn finish_elf(&mut self) {
+        if let Some(calories) = self.current.take() {
+            self.push_elf(calories);
+        }
+    }
+
+    /// Adds the total of the next elf, e.g. of one already summed up.
+    pub fn push_elf(&mut self, calories: u64) {
+        self.finish_elf();
+        let index = self.count;
+        self.count += 1;
+        self.total += calories;
+        if let Some(totals) = &mut self.totals {
+            totals.push(calories);
+        }
+        self.top.push(Reverse((calories, Reverse(index))));
+        if self.top.len() > self.k {
+            self.top.pop();
+        }
+    }
+
+    pub fn read(mut self, reader: impl BufRead) -> Result<CalorieSummary, String> {
+        for line in reader.lines() {
+            let line =
+                line.map_err(|err| format!("Cannot read line {}: {}", self.line + 1, err))?;
+            self.push_line(&line)?;
+        }
+        Ok(self.finish())
+    }
+
+    pub fn finish(mut self) -> CalorieSummary {
+        self.finish_elf();
+        let mut top = self
+            .top
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect::<Vec<_>>();
+        top.sort_by_key(|elf| (Reverse(elf.calories), elf.index));
+        CalorieSummary {
+            top,
+            count: self.count,
+            total: self.total,
+            median: self.totals.as_deref_mut().and_then(median),
+        }
+    }
+}
+
+fn median(totals: &mut [u64]) -> Option<f64> {
+    if totals.is_empty() {
+        return None;
+    }
+    let is_odd = totals.len() % 2 == 1;
+    let (lower, upper, _) = totals.select_nth_unstable(totals.len() / 2);
+    let upper = *upper as f64;
+    if is_odd {
+        Some(upper)
+    } else {
+        let lower = *lower.iter().max().expect("lower half is not empty") as f64;
+        Some((lower + upper) / 2.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieSummary {
+    /// Elves carrying the most from the first, ties are ordered as in the inventory.
+    pub top: Vec<Elf>,
+    pub count: usize,
+    pub total: u64,
+    /// Only known if the aggregator was created [CalorieAggregator::with_median].
+    pub median: Option<f64>,
+}
+
+impl CalorieSummary {
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.total as f64 / self.count as f64)
+    }
+
+    /// Calories of the `k` elves carrying the most, at most of all kept ones.
+    pub fn top_sum(&self, k: usize) -> u64 {
+        self.top.iter().take(k).map(|elf| elf.calories).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calories::{CalorieAggregator, Elf};
+
+    const INVENTORY: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn top_and_statistics() {
+        let summary = CalorieAggregator::new(2)
+            .with_median()
+            .read(INVENTORY.as_bytes())
+            .unwrap();
+        assert_eq!(
+            summary.top,
+            vec![
+                Elf {
+                    index: 3,
+                    calories: 24000
+                },
+                Elf {
+                    index: 2,
+                    calories: 11000
+                }
+            ]
+        );
+        assert_eq!(summary.top_sum(3), 35000);
+        assert_eq!(summary.count, 5);
+        assert_eq!(summary.mean(), Some(11000.0));
+        assert_eq!(summary.median, Some(10000.0));
+
+        let summary = CalorieAggregator::new(5)
+            .with_median()
+            .read("1\n\n\n3\n\n2\n\n3".as_bytes())
+            .unwrap();
+        let indices = summary.top.iter().map(|elf| elf.index).collect::<Vec<_>>();
+        assert_eq!(indices, vec![1, 3, 2, 0]);
+        assert_eq!(summary.median, Some(2.5));
+    }
+
+    #[test]
+    fn median_is_opt_in() {
+        let summary = CalorieAggregator::new(1)
+            .read(INVENTORY.as_bytes())
+            .unwrap();
+        assert_eq!(summary.median, None);
+        assert_eq!((summary.count, summary.total), (5, 55000));
+        assert_eq!(summary.top_sum(1), 24000);
+    }
+
+    #[test]
+    fn wrong_line() {
+        let error = CalorieAggregator::new(1)
+            .read("1\n\n2x\n".as_bytes())
+            .unwrap_err();
+        assert!(
+            error.starts_with("line 3, column 1: Cannot parse number"),
+            "{}",
+            error
+        );
+        assert_eq!(
+            CalorieAggregator::new(3).with_median().finish().median,
+            None
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::calories::{CalorieAggregator, CalorieSummary, Elf};
+use advent_util::parse::{number, ParseError};
+use std::cmp::Reverse;
+use std::str::FromStr;
+
+/// Snacks of every elf in the order of the inventory, unlike [CalorieAggregator] which keeps
+/// only totals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfInventory {
+    elves: Vec<Vec<u64>>,
+}
+
+/// Snack moved from one elf to another while rebalancing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnackMove {
+    pub calories: u64,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl ElfInventory {
+    pub fn new(elves: Vec<Vec<u64>>) -> Self {
+        Self { elves }
+    }
+
+    pub fn elves(&self) -> &[Vec<u64>] {
+        &self.elves
+    }
+
+    pub fn calories(&self, elf: usize) -> Option<u64> {
+        self.elves.get(elf).map(|snacks| snacks.iter().sum())
+    }
+
+    pub fn totals(&self) -> impl Iterator<Item = Elf> + '_ {
+        self.elves.iter().enumerate().map(|(index, snacks)| Elf {
+            index,
+            calories: snacks.iter().sum(),
+        })
+    }
+
+    /// The snacks are in memory anyway, so the summary has the median too.
+    pub fn summary(&self, k: usize) -> CalorieSummary {
+        let mut aggregator = CalorieAggregator::new(k).with_median();
+        for elf in self.totals() {
+            aggregator.push_elf(elf.calories);
+        }
+        aggregator.finish()
+    }
+
+    /// Elves carrying more than the threshold in the order of the inventory.
+    pub fn above(&self, threshold: u64) -> Vec<Elf> {
+        self.totals()
+            .filter(|elf| elf.calories > threshold)
+            .collect()
+    }
+
+    /// 1-based place of the elf by carried calories, elves carrying the same share it.
+    pub fn rank(&self, elf: usize) -> Option<usize> {
+        let calories = self.calories(elf)?;
+        Some(
+            self.totals()
+                .filter(|other| other.calories > calories)
+                .count()
+                + 1,
+        )
+    }
+
+    /// Moves snacks so no elf carries more than the limit. Overloaded elves give away their
+    /// biggest snacks first, and every snack goes to the elf with the most room left, so this
+    /// is greedy and may fail even if some other moves would fit.
+    pub fn rebalance(&mut self, limit: u64) -> Result<Vec<SnackMove>, String> {
+        let mut balanced = self.elves.clone();
+        let mut given_away = Vec::new();
+        for (from, snacks) in balanced.iter_mut().enumerate() {
+            snacks.sort_unstable();
+            while snacks.iter().sum::<u64>() > limit {
+                let calories = snacks.pop().expect("overloaded elf has snacks");
+                given_away.push((calories, from));
+            }
+        }
+
+        given_away.sort_by_key(|&(calories, from)| (Reverse(calories), from));
+        let mut moves = Vec::new();
+        for (calories, from) in given_away {
+            let (to, room) = balanced
+                .iter()
+                .enumerate()
+                .filter(|&(to, _)| to != from)
+                .map(|(to, snacks)| (to, limit - snacks.iter().sum::<u64>()))
+                .max_by_key(|&(to, room)| (room, Reverse(to)))
+                .ok_or_else(|| "There are no other elves to take snacks".to_string())?;
+            if room < calories {
+                return Err(format!(
+                    "Cannot find room for a snack of {} calories under {}",
+                    calories, limit
+                ));
+            }
+            balanced[to].push(calories);
+            moves.push(SnackMove { calories, from, to });
+        }
+
+        self.elves = balanced;
+        Ok(moves)
+    }
+}
+
+impl FromStr for ElfInventory {
+    type Err = ParseError;
+
+    /// Blank lines separate elves like in [CalorieAggregator::push_line], so several of them
+    /// in a row do not make elves without snacks.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elves = Vec::new();
+        let mut current = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                if !current.is_empty() {
+                    elves.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            current.push(number(line).map_err(|err| err.at(i + 1, 1))?);
+        }
+        if !current.is_empty() {
+            elves.push(current);
+        }
+        Ok(Self::new(elves))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calories::{CalorieAggregator, Elf};
+    use crate::inventory::{ElfInventory, SnackMove};
+
+    const INVENTORY: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn parse_with_line_numbers() {
+        let inventory = INVENTORY.parse::<ElfInventory>().unwrap();
+        assert_eq!(inventory.elves().len(), 5);
+        assert_eq!(inventory.elves()[2], vec![5000, 6000]);
+
+        let error = "1\n2\n\n3\nfour\n".parse::<ElfInventory>().unwrap_err();
+        assert_eq!(error.position.map(|position| position.line), Some(5));
+        assert_eq!(error.text, "four");
+    }
+
+    #[test]
+    fn blank_lines_like_aggregator() {
+        let input = "1\n\n\n3";
+        let inventory = input.parse::<ElfInventory>().unwrap();
+        assert_eq!(inventory.elves(), [vec![1], vec![3]]);
+        assert_eq!(
+            inventory.summary(5),
+            CalorieAggregator::new(5)
+                .with_median()
+                .read(input.as_bytes())
+                .unwrap()
+        );
+        let error = "\n\n1\n\n\nx".parse::<ElfInventory>().unwrap_err();
+        assert_eq!(error.position.map(|position| position.line), Some(6));
+
+        assert!("".parse::<ElfInventory>().unwrap().elves().is_empty());
+        assert!("\n\n".parse::<ElfInventory>().unwrap().elves().is_empty());
+    }
+
+    #[test]
+    fn queries() {
+        let inventory = INVENTORY.parse::<ElfInventory>().unwrap();
+        assert_eq!(
+            inventory.above(10000),
+            vec![
+                Elf {
+                    index: 2,
+                    calories: 11000
+                },
+                Elf {
+                    index: 3,
+                    calories: 24000
+                }
+            ]
+        );
+        assert_eq!(inventory.rank(3), Some(1));
+        assert_eq!(inventory.rank(1), Some(5));
+        assert_eq!(inventory.rank(5), None);
+        assert_eq!(inventory.summary(3).top_sum(3), 45000);
+
+        let tied = ElfInventory::new(vec![vec![5], vec![7], vec![5]]);
+        assert_eq!(tied.rank(0), Some(2));
+        assert_eq!(tied.rank(2), Some(2));
+    }
+
+    #[test]
+    fn rebalance_under_limit() {
+        let mut inventory = INVENTORY.parse::<ElfInventory>().unwrap();
+        assert_eq!(
+            inventory.rebalance(15000),
+            Ok(vec![SnackMove {
+                calories: 9000,
+                from: 3,
+                to: 1
+            }])
+        );
+        assert!(inventory.totals().all(|elf| elf.calories <= 15000));
+        assert_eq!(inventory.summary(5).total, 55000);
+
+        let mut inventory = ElfInventory::new(vec![vec![4, 4, 4], vec![1], vec![1]]);
+        assert_eq!(
+            inventory.rebalance(5),
+            Ok(vec![
+                SnackMove {
+                    calories: 4,
+                    from: 0,
+                    to: 1
+                },
+                SnackMove {
+                    calories: 4,
+                    from: 0,
+                    to: 2
+                }
+            ])
+        );
+        assert_eq!(inventory.elves(), [vec![4], vec![1, 4], vec![1, 4]]);
+
+        let mut inventory = ElfInventory::new(vec![vec![10, 10], vec![15]]);
+        assert!(inventory.rebalance(12).is_err());
+        assert_eq!(inventory.elves(), [vec![10, 10], vec![15]]);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::calories::{CalorieAggregator, CalorieSummary};
+use advent_util::solution::Solution;
+
+pub mod calories;
+pub mod inventory;
+
+/// Elves whose calories are summed in the second part.
+const TOP_ELVES: usize = 3;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = CalorieSummary;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        CalorieAggregator::new(TOP_ELVES).read(input.as_bytes())
+    }
+
+    fn part_one(&self, summary: &Self::Input) -> Self::PartOne {
+        summary.top_sum(1)
+    }
+
+    fn part_two(&self, summary: &Self::Input) -> Self::PartTwo {
+        summary.top_sum(TOP_ELVES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day1;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day1, env!("CARGO_MANIFEST_DIR"));
+    }
+}
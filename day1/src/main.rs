@@ -1,29 +1,88 @@
-use advent_util::read_input;
+use advent_util::output::OutputFormat;
+use advent_util::solution::{
+    exit_with_error, exit_with_usage, run_with_args, take_flag, take_option, MainArgs,
+};
+use day1::calories::{CalorieAggregator, CalorieSummary, Elf};
+use day1::Day1;
+use serde::Serialize;
+use std::env;
+
+/// Options of day 1 in front of the ones of [MainArgs].
+const OPTIONS: &str = "[--top K [--median]] ";
 
 fn main() {
-    let input = read_input(1).unwrap();
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let top = take_top(&mut args).unwrap_or_else(|err| exit_with_usage(1, OPTIONS, &err));
+    let median = take_flag(&mut args, "--median");
+    let args = MainArgs::parse(1, args).unwrap_or_else(|err| exit_with_usage(1, OPTIONS, &err));
+    match top {
+        Some(k) => print_top(&args, k, median),
+        None if median => exit_with_usage(1, OPTIONS, "--median needs --top"),
+        None => run_with_args(&Day1, &args),
+    }
+}
 
-    let elf_snacks = input.split("\n")
-        .fold(vec![], |mut acc, line| {
-            if line.is_empty() {
-                acc.push(vec![]);
-            } else {
-                let snack: u32 = line.parse().expect(&format!("Wrong line {}", line));
-                if let Some(current_elf) = acc.last_mut() {
-                    current_elf.push(snack);
-                } else {
-                    acc.push(vec![snack]);
-                }
-            }
-            acc
-        });
+/// `--top K` asks for the statistics of the inventory read as a stream.
+fn take_top(args: &mut Vec<String>) -> Result<Option<usize>, String> {
+    take_option(args, "--top")?
+        .map(|k| {
+            k.parse()
+                .map_err(|err| format!("Wrong number of elves '{}': {}", k, err))
+        })
+        .transpose()
+}
 
-    let mut elf_calories = elf_snacks.iter()
-        .map(|elf| elf.iter().sum())
-        .collect::<Vec<u32>>();
-    elf_calories.sort_by(|calories1, calories2| calories2.cmp(calories1));
-    let sum_of_most_3: u32 = elf_calories.iter().take(3).sum();
+/// Statistics of the inventory read as a stream, printed with `--format json`.
+#[derive(Serialize)]
+struct TopRecord<'a> {
+    /// Elves are counted from zero.
+    top: &'a [Elf],
+    top_total: u64,
+    count: usize,
+    mean: Option<f64>,
+    median: Option<f64>,
+}
+
+/// Lists the `k` elves carrying the most. Only with `median` the total of every elf is kept
+/// in memory, otherwise the median is unknown.
+fn print_top(args: &MainArgs, k: usize, median: bool) {
+    let reader = args
+        .input
+        .reader()
+        .unwrap_or_else(|err| exit_with_error(err));
+    let mut aggregator = CalorieAggregator::new(k);
+    if median {
+        aggregator = aggregator.with_median();
+    }
+    let summary = aggregator
+        .read(reader)
+        .unwrap_or_else(|err| exit_with_error(err));
+
+    match args.format {
+        OutputFormat::Text => print_text(&summary, k),
+        OutputFormat::Json => {
+            let record = TopRecord {
+                top: &summary.top,
+                top_total: summary.top_sum(k),
+                count: summary.count,
+                mean: summary.mean(),
+                median: summary.median,
+            };
+            println!("{}", serde_json::to_string(&record).unwrap());
+        }
+    }
+}
 
-    println!("Max elf snack calories = {}", elf_calories[0]);
-    println!("Sum of top 3 is {}", sum_of_most_3);
+fn print_text(summary: &CalorieSummary, k: usize) {
+    for (rank, elf) in summary.top.iter().enumerate() {
+        println!("{}. elf {}: {}", rank + 1, elf.index + 1, elf.calories);
+    }
+    println!("Top {} total: {}", summary.top.len(), summary.top_sum(k));
+    println!("Elves: {}", summary.count);
+    if let Some(mean) = summary.mean() {
+        println!("Mean: {:.1}", mean);
+    }
+    if let Some(median) = summary.median {
+        println!("Median: {:.1}", median);
+    }
 }
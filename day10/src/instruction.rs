@@ -1,6 +1,5 @@
 use crate::instruction::Instruction::{Addx, Noop};
-use std::collections::HashSet;
-use std::iter::repeat;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy)]
@@ -10,10 +9,10 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    fn cycles(&self) -> impl Iterator<Item = ()> {
+    fn cycles(&self) -> usize {
         match self {
-            Noop => repeat(()).take(1),
-            Addx(_) => repeat(()).take(2),
+            Noop => 1,
+            Addx(_) => 2,
         }
     }
 
@@ -24,23 +23,32 @@ impl Instruction {
     }
 }
 
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Noop => write!(f, "noop"),
+            Addx(value) => write!(f, "addx {}", value),
+        }
+    }
+}
+
 impl FromStr for Instruction {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(_) = s.strip_prefix("noop") {
+        if s.strip_prefix("noop").is_some() {
             Ok(Noop)
         } else if let Some(rest) = s.strip_prefix("addx ") {
             rest.parse::<i32>()
                 .map_err(|err| format!("Cannot parse addx argument '{}': {}", rest, err))
-                .map(|v| Addx(v))
+                .map(Addx)
         } else {
             Err(format!("Unknown command: {}", s))
         }
     }
 }
 
-const CRT_WIDTH: i32 = 40;
+pub(crate) const CRT_WIDTH: i32 = 40;
 
 fn cycle_to_column(cycle: i32) -> i32 {
     let rem = cycle % CRT_WIDTH;
@@ -51,33 +59,95 @@ fn cycle_to_column(cycle: i32) -> i32 {
     }
 }
 
+const SIGNAL_STRENGTH_CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
+
 pub struct Cpu;
 
 impl Cpu {
-    pub fn execute(&self, program: &[Instruction]) -> i32 {
-        let mut cycle = 1i32;
-        let mut register = 1i32;
-
-        let signal_strength_cycles = HashSet::from([20, 60, 100, 140, 180, 220]);
-        let mut signal_strength = 0;
-        for instruction in program {
-            for _ in instruction.cycles() {
-                if signal_strength_cycles.contains(&cycle) {
-                    signal_strength += cycle * register;
-                }
-                if (register..register + 3).contains(&cycle_to_column(cycle)) {
-                    print!("#");
-                } else {
-                    print!(".")
-                }
-                cycle += 1;
-                if (cycle - 1) % 40 == 0 {
-                    println!();
-                }
-            }
-            instruction.apply_to_register(&mut register);
+    /// Returns the sum of signal strengths and the image drawn on the CRT.
+    pub fn execute(&self, program: &[Instruction]) -> (i32, String) {
+        let mut execution = Execution::new(program);
+        while execution.tick() {}
+        (
+            execution.signal_strength,
+            execution.crt.trim_end().to_string(),
+        )
+    }
+}
+
+/// State of the program running cycle by cycle.
+#[derive(Debug, Clone)]
+pub struct Execution {
+    program: Vec<Instruction>,
+    /// Index of the running instruction.
+    instruction: usize,
+    /// Cycles until the running instruction is finished.
+    cycles_left: usize,
+    cycle: i32,
+    register: i32,
+    signal_strength: i32,
+    crt: String,
+}
+
+impl Execution {
+    pub fn new(program: &[Instruction]) -> Self {
+        Self {
+            program: program.to_vec(),
+            instruction: 0,
+            cycles_left: program.first().map_or(0, Instruction::cycles),
+            cycle: 1,
+            register: 1,
+            signal_strength: 0,
+            crt: String::new(),
+        }
+    }
+
+    /// Runs a cycle, `false` if the program is finished.
+    pub fn tick(&mut self) -> bool {
+        let Some(instruction) = self.current_instruction() else {
+            return false;
+        };
+
+        if SIGNAL_STRENGTH_CYCLES.contains(&self.cycle) {
+            self.signal_strength += self.cycle * self.register;
         }
+        if (self.register..self.register + 3).contains(&cycle_to_column(self.cycle)) {
+            self.crt.push('#');
+        } else {
+            self.crt.push('.');
+        }
+        self.cycle += 1;
+        if (self.cycle - 1) % CRT_WIDTH == 0 {
+            self.crt.push('\n');
+        }
+
+        self.cycles_left -= 1;
+        if self.cycles_left == 0 {
+            instruction.apply_to_register(&mut self.register);
+            self.instruction += 1;
+            self.cycles_left = self.current_instruction().map_or(0, |i| i.cycles());
+        }
+        true
+    }
+
+    pub fn current_instruction(&self) -> Option<Instruction> {
+        self.program.get(self.instruction).copied()
+    }
+
+    pub fn cycle(&self) -> i32 {
+        self.cycle
+    }
+
+    pub fn register(&self) -> i32 {
+        self.register
+    }
+
+    pub fn signal_strength(&self) -> i32 {
+        self.signal_strength
+    }
 
-        signal_strength
+    /// Pixels drawn so far, a line per row of the CRT.
+    pub fn crt(&self) -> &str {
+        &self.crt
     }
 }
@@ -0,0 +1,46 @@
+use crate::instruction::{Cpu, Instruction};
+use advent_util::solution::Solution;
+
+pub mod instruction;
+pub mod simulation;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type PartOne = i32;
+    /// Image drawn on the CRT.
+    type PartTwo = String;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        input
+            .lines()
+            .map(|line| line.parse::<Instruction>())
+            .collect()
+    }
+
+    fn part_one(&self, program: &Self::Input) -> Self::PartOne {
+        let (sum_of_signal_strengths, _) = Cpu.execute(program);
+        sum_of_signal_strengths
+    }
+
+    fn part_two(&self, program: &Self::Input) -> Self::PartTwo {
+        let (_, crt) = Cpu.execute(program);
+        crt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day10, env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -1,16 +1,15 @@
-use crate::instruction::{Cpu, Instruction};
-use advent_util::read_input;
-
-mod instruction;
+use advent_util::solution::{run_main, Solution};
+use advent_util::tui;
+use day10::instruction::Execution;
+use day10::Day10;
 
 fn main() {
-    let program = read_input(10)
-        .unwrap()
-        .lines()
-        .map(|line| line.parse::<Instruction>().unwrap())
-        .collect::<Vec<_>>();
-    let cpu = Cpu;
-    let sum_of_signal_strengths = cpu.execute(&program);
-
-    println!("Sum of signal strengths: {}", sum_of_signal_strengths);
+    let Some(args) = tui::from_env(10) else {
+        run_main(&Day10);
+        return;
+    };
+    let input = args.input.read().unwrap_or_else(|err| panic!("{}", err));
+    let program = Day10.parse(&input).unwrap_or_else(|err| panic!("{}", err));
+    tui::explore("CRT", Execution::new(&program))
+        .unwrap_or_else(|err| panic!("Terminal error: {}", err));
 }
@@ -0,0 +1,64 @@
+use crate::instruction::{Execution, CRT_WIDTH};
+use advent_util::tui::Simulation;
+
+impl Simulation for Execution {
+    fn step(&mut self) -> bool {
+        self.tick()
+    }
+
+    /// The CRT with the sprite under it.
+    fn render(&self) -> String {
+        let sprite = (0..CRT_WIDTH)
+            .map(|column| {
+                if (self.register() - 1..=self.register() + 1).contains(&column) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+        format!("{}\n\nSprite:\n{}", self.crt(), sprite)
+    }
+
+    fn status(&self) -> Vec<(String, String)> {
+        let instruction = self
+            .current_instruction()
+            .map_or("finished".to_string(), |instruction| {
+                instruction.to_string()
+            });
+        vec![
+            ("Cycle".to_string(), self.cycle().to_string()),
+            ("X".to_string(), self.register().to_string()),
+            ("Instruction".to_string(), instruction),
+            (
+                "Signal strength".to_string(),
+                self.signal_strength().to_string(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instruction::{Execution, Instruction};
+    use advent_util::tui::{Explorer, Simulation};
+
+    #[test]
+    fn step_through_cycles() {
+        let program = ["noop", "addx 3", "addx -5"].map(|i| i.parse::<Instruction>().unwrap());
+        let mut explorer = Explorer::new(Execution::new(&program));
+        explorer.forward(3);
+        assert_eq!(explorer.current().register(), 4);
+        assert_eq!(explorer.current().crt(), "###");
+        assert!(explorer
+            .current()
+            .render()
+            .ends_with(&format!("...###{}", ".".repeat(34))));
+
+        explorer.jump_to_end();
+        assert_eq!(explorer.last_step(), Some(5));
+        assert_eq!(explorer.current().register(), -1);
+        explorer.back(4);
+        assert_eq!(explorer.current().cycle(), 2);
+    }
+}
@@ -1,45 +1,88 @@
 use crate::monkeys::{Monkey, WorryReduction};
+use std::cmp::Reverse;
 
-#[derive(Debug)]
-pub struct KeepAway<'a> {
-    monkeys: &'a mut [Monkey],
+#[derive(Debug, Clone)]
+pub struct KeepAway {
+    monkeys: Vec<Monkey>,
     worry_reduction: WorryReduction,
+    inspected: Vec<usize>,
+    round: usize,
+    /// Monkey whose turn it is.
+    turn: usize,
 }
 
-#[derive(Debug, Default, Clone)]
-struct Statistics {
-    items_inspected: usize,
+/// Item thrown from one monkey to another with its new worry level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Throw {
+    pub from: usize,
+    pub to: usize,
+    pub worry: u64,
 }
 
-impl<'a> KeepAway<'a> {
-    pub fn new(monkeys: &'a mut [Monkey], worry_reduction: WorryReduction) -> Self {
+impl KeepAway {
+    pub fn new(monkeys: Vec<Monkey>, worry_reduction: WorryReduction) -> Self {
         Self {
+            inspected: vec![0; monkeys.len()],
             monkeys,
             worry_reduction,
+            round: 0,
+            turn: 0,
         }
     }
 
     pub fn play(&mut self, rounds: usize) -> usize {
-        let mut statistics = vec![Statistics::default(); self.monkeys.len()];
-        for _ in 0..rounds {
-            for monkey_id in 0..self.monkeys.len() {
-                while let Some(item) = self.monkeys[monkey_id].items_mut().pop_front() {
-                    statistics[monkey_id].items_inspected += 1;
-
-                    let (new, new_monkey) = {
-                        let monkey = &self.monkeys[monkey_id];
-                        let new = monkey.execute_operation(item, self.worry_reduction);
-                        let new_monkey = monkey.next_monkey(new);
-                        (new, new_monkey)
-                    };
-                    self.monkeys[new_monkey].items_mut().push_back(new);
-                }
-            }
+        while self.round < rounds {
+            self.advance();
         }
+        self.monkey_business()
+    }
+
+    /// Throws the next item of the monkey whose turn it is, or ends the turn if it has none.
+    pub fn advance(&mut self) -> Option<Throw> {
+        let Some(item) = self.monkeys[self.turn].items_mut().pop_front() else {
+            self.turn += 1;
+            if self.turn == self.monkeys.len() {
+                self.turn = 0;
+                self.round += 1;
+            }
+            return None;
+        };
+        self.inspected[self.turn] += 1;
+
+        let monkey = &self.monkeys[self.turn];
+        let worry = monkey.execute_operation(item, self.worry_reduction);
+        let throw = Throw {
+            from: self.turn,
+            to: monkey.next_monkey(worry),
+            worry,
+        };
+        self.monkeys[throw.to].items_mut().push_back(worry);
+        Some(throw)
+    }
+
+    /// Product of the two highest numbers of inspected items.
+    pub fn monkey_business(&self) -> usize {
+        let mut inspected = self.inspected.clone();
+        inspected.sort_by_key(|&count| Reverse(count));
+        assert!(inspected.len() >= 2);
+        inspected[0] * inspected[1]
+    }
+
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+
+    /// Number of items inspected by every monkey.
+    pub fn inspected(&self) -> &[usize] {
+        &self.inspected
+    }
+
+    /// Number of finished rounds.
+    pub fn round(&self) -> usize {
+        self.round
+    }
 
-        statistics.sort_by(|item, other| other.items_inspected.cmp(&item.items_inspected));
-        assert!(statistics.len() >= 2);
-        // dbg!(&statistics);
-        statistics[0].items_inspected * statistics[1].items_inspected
+    pub fn turn(&self) -> usize {
+        self.turn
     }
 }
@@ -0,0 +1,50 @@
+use crate::keep_away::KeepAway;
+use crate::monkeys::{Monkey, WorryReduction};
+use advent_util::parse::blocks;
+use advent_util::solution::Solution;
+
+pub mod keep_away;
+pub mod monkeys;
+pub mod simulation;
+
+pub const ROUNDS: usize = 20;
+const NEW_GAME: usize = 10000;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(blocks(input, str::parse)?)
+    }
+
+    fn part_one(&self, monkeys: &Self::Input) -> Self::PartOne {
+        let mut keep_away = KeepAway::new(monkeys.clone(), WorryReduction::BoringMonkey);
+        keep_away.play(ROUNDS)
+    }
+
+    fn part_two(&self, monkeys: &Self::Input) -> Self::PartTwo {
+        let module =
+            WorryReduction::ModuleOperation(monkeys.iter().map(|m| m.get_throw_test()).product());
+        let mut keep_away = KeepAway::new(monkeys.clone(), module);
+        keep_away.play(NEW_GAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day11, env!("CARGO_MANIFEST_DIR"));
+    }
+}
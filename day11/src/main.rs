@@ -1,36 +1,15 @@
-use crate::keep_away::KeepAway;
-use crate::monkeys::{Monkey, WorryReduction};
-use advent_util::read_input;
-
-mod keep_away;
-mod monkeys;
-
-const NEW_GAME: usize = 10000;
+use advent_util::solution::{run_main, Solution};
+use advent_util::tui;
+use day11::simulation::KeepAwaySimulation;
+use day11::{Day11, ROUNDS};
 
 fn main() {
-    let mut monkeys = read_input(11)
-        .unwrap()
-        .split("\n\n")
-        .map(|input| input.parse::<Monkey>().unwrap())
-        .collect::<Vec<_>>();
-    let mut other_monkeys = monkeys.clone();
-    // dbg!(&monkeys);
-
-    let monkey_business = {
-        let mut keep_away = KeepAway::new(&mut monkeys, WorryReduction::BoringMonkey);
-        keep_away.play(20)
-    };
-    println!("Monkey business: {}", monkey_business);
-
-    let monkey_business = {
-        let module = WorryReduction::ModuleOperation(
-            other_monkeys.iter().map(|m| m.get_throw_test()).product(),
-        );
-        let mut keep_away = KeepAway::new(&mut other_monkeys, module);
-        keep_away.play(NEW_GAME)
+    let Some(args) = tui::from_env(11) else {
+        run_main(&Day11);
+        return;
     };
-    println!(
-        "Monkey business after {} rounds: {}",
-        NEW_GAME, monkey_business
-    );
+    let input = args.input.read().unwrap_or_else(|err| panic!("{}", err));
+    let monkeys = Day11.parse(&input).unwrap_or_else(|err| panic!("{}", err));
+    tui::explore("Keep away", KeepAwaySimulation::new(&monkeys, ROUNDS))
+        .unwrap_or_else(|err| panic!("Terminal error: {}", err));
 }
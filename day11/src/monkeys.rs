@@ -1,3 +1,4 @@
+use advent_util::parse::{number, numbers, parse_after, ParseError};
 use std::collections::VecDeque;
 use std::str::FromStr;
 
@@ -10,6 +11,10 @@ pub struct Monkey {
 }
 
 impl Monkey {
+    pub fn items(&self) -> &VecDeque<u64> {
+        &self.items
+    }
+
     pub fn items_mut(&mut self) -> &mut VecDeque<u64> {
         &mut self.items
     }
@@ -23,7 +28,7 @@ impl Monkey {
     }
 
     pub fn next_monkey(&self, item: u64) -> usize {
-        if item % self.throw.test == 0 {
+        if item.is_multiple_of(self.throw.test) {
             self.throw.monkey_success_id
         } else {
             self.throw.monkey_failure_id
@@ -85,7 +90,7 @@ impl WorryReduction {
 }
 
 impl FromStr for Monkey {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Parser::new(s).parse()
@@ -95,16 +100,24 @@ impl FromStr for Monkey {
 struct Parser<'a> {
     input: &'a str,
     lines: Box<dyn Iterator<Item = &'a str> + 'a>,
+    /// 1-based number of the last read line in the block.
+    line: usize,
+    /// Leading whitespace of the last read line, which is skipped.
+    indent: usize,
 }
 
 impl<'a> Parser<'a> {
     fn new(input: &'a str) -> Self {
-        let lines = input.lines().map(|line| line.trim());
-        let lines = Box::new(lines);
-        Self { input, lines }
+        let lines = Box::new(input.lines());
+        Self {
+            input,
+            lines,
+            line: 0,
+            indent: 0,
+        }
     }
 
-    fn parse(mut self) -> Result<Monkey, <Monkey as FromStr>::Err> {
+    fn parse(mut self) -> Result<Monkey, ParseError> {
         let id = self.parse_id()?;
         let items = self.parse_items()?;
         let operation = self.parse_operation()?;
@@ -117,113 +130,56 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_id(&mut self) -> Result<usize, <Monkey as FromStr>::Err> {
+    fn parse_id(&mut self) -> Result<usize, ParseError> {
         let line = self.next_line(1)?;
-        if let Some(rest) = line.strip_prefix("Monkey ") {
-            rest.strip_suffix(":")
-                .ok_or_else(|| format!("Malformed input!: 'Monkey {}'", rest))
-                .and_then(|num| {
-                    num.parse::<usize>()
-                        .map_err(|err| format!("Cannot parse ID '{}': {}", num, err))
-                })
-        } else {
-            Err(format!("Expected 'Monkey' but '{}' received", line))
-        }
+        let id = parse_after(line, "Monkey ", |id| {
+            id.strip_suffix(':')
+                .ok_or_else(|| ParseError::new("Expected ':'", id))
+                .and_then(number)
+        });
+        self.locate(id)
     }
 
-    fn parse_items(&mut self) -> Result<VecDeque<u64>, <Monkey as FromStr>::Err> {
-        self.next_line(2)
-            .and_then(|line| {
-                line.strip_prefix("Starting items: ")
-                    .ok_or_else(|| format!("Expected 'Starting items', but '{}' recieved!", line))
-            })
-            .and_then(|items| {
-                items
-                    .split(", ")
-                    .fold(Ok(VecDeque::new()), |res, item| match res {
-                        Ok(mut vec) => item
-                            .parse::<u64>()
-                            .map_err(|err| {
-                                format!("Cannot parse items '{}' because: {}", item, err)
-                            })
-                            .map(|item| {
-                                vec.push_back(item);
-                                vec
-                            }),
-                        Err(_) => res,
-                    })
-            })
+    fn parse_items(&mut self) -> Result<VecDeque<u64>, ParseError> {
+        let line = self.next_line(2)?;
+        let items = parse_after(line, "Starting items: ", |items| numbers(items, ","));
+        self.locate(items).map(VecDeque::from)
     }
 
-    fn parse_operation(&mut self) -> Result<Operation, <Monkey as FromStr>::Err> {
-        self.next_line(3)
-            .and_then(|line| {
-                line.strip_prefix("Operation: new = old ").ok_or_else(|| {
-                    format!("Expected 'Operation: new = old ' but '{}' received", line)
-                })
-            })
-            .and_then(|op_and_num| {
-                if let Some(rest) = op_and_num.strip_prefix("* ") {
-                    Ok((Op::Mul, rest))
-                } else if let Some(rest) = op_and_num.strip_prefix("+ ") {
-                    Ok((Op::Plus, rest))
-                } else {
-                    Err(format!("Unknown operation: {}", op_and_num))
-                }
-            })
-            .and_then(|(op, arg)| {
+    fn parse_operation(&mut self) -> Result<Operation, ParseError> {
+        let line = self.next_line(3)?;
+        let operation = parse_after(line, "Operation: new = old ", |op_and_num| {
+            let (op, prefix) = if op_and_num.starts_with("* ") {
+                Ok((Op::Mul, "* "))
+            } else if op_and_num.starts_with("+ ") {
+                Ok((Op::Plus, "+ "))
+            } else {
+                Err(ParseError::new("Unknown operation", op_and_num))
+            }?;
+
+            let arg = parse_after(op_and_num, prefix, |arg| {
                 if arg == "old" {
-                    Ok(Operation {
-                        op,
-                        arg: OpArg::Old,
-                    })
+                    Ok(OpArg::Old)
                 } else {
-                    arg.parse::<u64>()
-                        .map_err(|err| {
-                            format!("Cannot parse operation argument '{}' because: {}", arg, err)
-                        })
-                        .map(|value| Operation {
-                            op,
-                            arg: OpArg::Int(value),
-                        })
+                    number(arg).map(OpArg::Int)
                 }
-            })
+            })?;
+            Ok(Operation { op, arg })
+        });
+        self.locate(operation)
     }
 
-    fn parse_throw_condition(&mut self) -> Result<ThrowCondition, <Monkey as FromStr>::Err> {
+    fn parse_throw_condition(&mut self) -> Result<ThrowCondition, ParseError> {
         let line = self.next_line(4)?;
-        let test = if let Some(rest) = line.strip_prefix("Test: divisible by ") {
-            rest.parse::<u64>()
-                .map_err(|err| format!("Cannot parse test '{}' because: {}", rest, err))
-        } else {
-            Err(format!(
-                "Expected 'Test: divisible by ' but '{}' received",
-                line
-            ))
-        }?;
+        let test = self.locate(parse_after(line, "Test: divisible by ", number))?;
 
         let line = self.next_line(5)?;
-        let monkey_success_id = if let Some(rest) = line.strip_prefix("If true: throw to monkey ") {
-            rest.parse::<usize>()
-                .map_err(|err| format!("Cannot parse then branch '{}' because {}", rest, err))
-        } else {
-            Err(format!(
-                "Expected 'If true: throw to monkey ' but '{}' received",
-                line
-            ))
-        }?;
+        let monkey_success_id =
+            self.locate(parse_after(line, "If true: throw to monkey ", number))?;
 
         let line = self.next_line(6)?;
-        let monkey_failure_id = if let Some(rest) = line.strip_prefix("If false: throw to monkey ")
-        {
-            rest.parse::<usize>()
-                .map_err(|err| format!("Cannot parse else branch '{}' because {}", rest, err))
-        } else {
-            Err(format!(
-                "Expected 'If false: throw to monkey ' but {} received",
-                line
-            ))
-        }?;
+        let monkey_failure_id =
+            self.locate(parse_after(line, "If false: throw to monkey ", number))?;
         Ok(ThrowCondition {
             test,
             monkey_success_id,
@@ -231,19 +187,29 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn next_line(&mut self, expected_amount: usize) -> Result<&str, String> {
-        self.lines.next().ok_or_else(|| {
-            format!(
-                "Malformed input! Expected at least {} lines: {}",
-                expected_amount, self.input
+    fn next_line(&mut self, expected_amount: usize) -> Result<&'a str, ParseError> {
+        let line = self.lines.next().ok_or_else(|| {
+            ParseError::new(
+                format!("Expected at least {} lines", expected_amount),
+                self.input,
             )
-        })
+        })?;
+        let trimmed = line.trim_start();
+        self.line = expected_amount;
+        self.indent = line.len() - trimmed.len();
+        Ok(trimmed.trim_end())
+    }
+
+    /// Places the error of the last read line into the block.
+    fn locate<T>(&self, result: Result<T, ParseError>) -> Result<T, ParseError> {
+        result.map_err(|err| err.offset(self.line, self.indent + 1))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::monkeys::{Monkey, Op, OpArg, Operation, Parser, ThrowCondition};
+    use advent_util::parse::blocks;
     use std::collections::VecDeque;
 
     const INPUT: &str = "Monkey 0:
@@ -326,4 +292,13 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn parse_error_position() {
+        let error = blocks(&INPUT.replace("by 23", "by x23"), str::parse::<Monkey>).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 22: Cannot parse number: invalid digit found in string: 'x23'"
+        );
+    }
 }
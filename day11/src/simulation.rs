@@ -0,0 +1,126 @@
+use crate::keep_away::{KeepAway, Throw};
+use crate::monkeys::{Monkey, WorryReduction};
+use advent_util::tui::Simulation;
+
+/// Game with the rules of part one, an item thrown or a turn ended per step.
+#[derive(Debug, Clone)]
+pub struct KeepAwaySimulation {
+    keep_away: KeepAway,
+    rounds: usize,
+    last_throw: Option<Throw>,
+}
+
+impl KeepAwaySimulation {
+    pub fn new(monkeys: &[Monkey], rounds: usize) -> Self {
+        Self {
+            keep_away: KeepAway::new(monkeys.to_vec(), WorryReduction::BoringMonkey),
+            rounds,
+            last_throw: None,
+        }
+    }
+}
+
+impl Simulation for KeepAwaySimulation {
+    fn step(&mut self) -> bool {
+        if self.keep_away.round() == self.rounds {
+            return false;
+        }
+        self.last_throw = self.keep_away.advance();
+        true
+    }
+
+    /// Items of every monkey, the one whose turn it is marked by `>`.
+    fn render(&self) -> String {
+        self.keep_away
+            .monkeys()
+            .iter()
+            .enumerate()
+            .map(|(id, monkey)| {
+                let marker = if id == self.keep_away.turn() {
+                    '>'
+                } else {
+                    ' '
+                };
+                let items = monkey
+                    .items()
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{} Monkey {}: {}", marker, id, items)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn status(&self) -> Vec<(String, String)> {
+        let last_throw = self.last_throw.map_or("-".to_string(), |throw| {
+            format!("{} from {} to {}", throw.worry, throw.from, throw.to)
+        });
+        let mut status = vec![
+            (
+                "Round".to_string(),
+                format!("{} of {}", self.keep_away.round() + 1, self.rounds),
+            ),
+            ("Turn".to_string(), self.keep_away.turn().to_string()),
+            ("Last throw".to_string(), last_throw),
+            (
+                "Monkey business".to_string(),
+                self.keep_away.monkey_business().to_string(),
+            ),
+        ];
+        status.extend(
+            self.keep_away
+                .inspected()
+                .iter()
+                .enumerate()
+                .map(|(id, count)| (format!("Inspected by {}", id), count.to_string())),
+        );
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::KeepAwaySimulation;
+    use crate::Day11;
+    use advent_util::solution::Solution;
+    use advent_util::tui::{Explorer, Simulation};
+
+    const MONKEYS: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+    #[test]
+    fn step_through_throws() {
+        let monkeys = Day11.parse(MONKEYS).unwrap();
+        let mut explorer = Explorer::new(KeepAwaySimulation::new(&monkeys, 1));
+        explorer.forward(1);
+        assert_eq!(
+            explorer.current().render(),
+            "> Monkey 0: 98\n  Monkey 1: 54, 500"
+        );
+        explorer.forward(2);
+        assert_eq!(
+            explorer.current().render(),
+            "  Monkey 0: \n> Monkey 1: 54, 500, 620"
+        );
+
+        explorer.jump_to_end();
+        assert_eq!(explorer.last_step(), Some(7));
+        assert_eq!(
+            explorer.current().render(),
+            "> Monkey 0: 20, 168, 208\n  Monkey 1: "
+        );
+    }
+}
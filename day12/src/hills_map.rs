@@ -1,166 +1,91 @@
-use array2d::Array2D;
-use std::cmp::Ordering;
-use std::collections::VecDeque;
+use advent_util::grid::{Grid, Position};
+use advent_util::parse::ParseError;
+use advent_util::point::{Direction, Point2};
+use advent_util::search::bfs;
 
 #[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
 pub struct Location {
-    row: usize,
-    column: usize,
+    point: Point2,
     height: u8,
 }
 
 impl Location {
-    fn as_index(&self) -> (usize, usize) {
-        (self.row, self.column)
+    pub fn point(&self) -> Point2 {
+        self.point
+    }
+
+    fn as_index(&self) -> Position {
+        self.point
+            .to_position()
+            .expect("locations are inside of the map")
     }
 }
 
 #[derive(Debug)]
 pub struct HillsMap {
-    hills: Array2D<Location>,
+    hills: Grid<Location>,
 }
 
 impl HillsMap {
-    pub fn parse_map(s: &str) -> (Self, Location, Location) {
-        let mut hills = vec![];
-        let mut start = None;
-        let mut end = None;
-        for (row, line) in s.trim().split("\n").enumerate() {
-            let mut current_row = vec![];
-            for (column, height_char) in line.chars().enumerate() {
-                let height = match height_char {
-                    'S' => {
-                        start = Some(Location {
-                            row,
-                            column,
-                            height: 0,
-                        });
-                        0
-                    }
-                    'E' => {
-                        let height = 'z' as u8 - 'a' as u8;
-                        end = Some(Location {
-                            row,
-                            column,
-                            height,
-                        });
-                        height
-                    }
-                    _ => height_char as u8 - 'a' as u8,
-                };
-
-                current_row.push(Location {
-                    row,
-                    column,
-                    height,
-                })
+    pub fn parse_map(s: &str) -> Result<(Self, Location, Location), ParseError> {
+        let map = Grid::parse(s.trim(), |c| match c {
+            'S' | 'E' | 'a'..='z' => Ok(c),
+            _ => Err(ParseError::new("Unexpected height", &c.to_string())),
+        })?;
+        let find = |mark: char| {
+            map.iter()
+                .find(|(_, c)| **c == mark)
+                .map(|(position, _)| position)
+                .ok_or_else(|| ParseError::new(format!("Cannot find '{}'", mark), s))
+        };
+        let (start, end) = (find('S')?, find('E')?);
+
+        let hills = Grid::from_fn(map.height(), map.width(), |(row, column)| {
+            let height = match map[(row, column)] {
+                'S' => b'a',
+                'E' => b'z',
+                c => c as u8,
+            } - b'a';
+            Location {
+                point: Point2::from_position((row, column)),
+                height,
             }
-            hills.push(current_row);
-        }
-
-        let hills = Array2D::from_rows(&hills).unwrap();
+        });
+        let (start, end) = (hills[start], hills[end]);
 
-        let start = start.unwrap();
-        let end = end.unwrap();
-
-        (Self { hills }, start, end)
-    }
-
-    fn bfs<'a, F, I>(&'a self, from: Location, neighbours: F) -> Array2D<Option<(usize, Location)>>
-    where
-        I: Iterator<Item = Location> + 'a,
-        F: Fn(Location) -> I,
-    {
-        let mut distance =
-            Array2D::filled_with(None, self.hills.column_len(), self.hills.row_len());
-
-        distance[from.as_index()] = Some((0, from));
-
-        let mut queue = VecDeque::from([(from, 0)]);
-        while let Some((current, current_distance)) = queue.pop_front() {
-            for neighbour in neighbours(current) {
-                let neighbour_distance = distance[neighbour.as_index()];
-                match neighbour_distance {
-                    None => {
-                        let new_distance = current_distance + 1;
-                        distance[neighbour.as_index()] = Some((new_distance, current));
-                        queue.push_back((neighbour, new_distance));
-                    }
-                    Some((neighbour_distance, _)) if neighbour_distance > current_distance + 1 => {
-                        let new_distance = current_distance + 1;
-                        distance[neighbour.as_index()] = Some((new_distance, current));
-                        queue.push_back((neighbour, new_distance));
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        distance
+        Ok((Self { hills }, start, end))
     }
 
     pub fn find_path(&self, from: Location, to: Location) -> Option<(usize, Vec<Location>)> {
-        let distance = self.bfs(from, |location| self.neighbours(location));
-
-        if let Some((len, _)) = distance[to.as_index()] {
-            Some((len, Self::restore_path(&distance, to)))
-        } else {
-            None
-        }
+        let distances = bfs(from, |location| self.neighbours(*location));
+        Some((distances.distance(&to)?, distances.path_to(&to)?))
     }
 
     pub fn find_shortest_path_from_lowest(
         &self,
         to: Location,
     ) -> Option<(Location, usize, Vec<Location>)> {
-        let distance = self.bfs(to, |location| self.backward_neighbours(location));
-
-        let mut min: Option<(usize, Location)> = None;
-        for row in self.hills.rows_iter() {
-            for location in row {
-                if *location != to && location.height == 0 {
-                    let (current_distance, _) = if let Some(dist) = distance[location.as_index()] {
-                        dist
-                    } else {
-                        continue;
-                    };
-                    if let Some((min_distance, _)) = min {
-                        if min_distance > current_distance {
-                            min = Some((current_distance, *location));
-                        }
-                    } else {
-                        min = Some((current_distance, *location));
-                    }
-                }
-            }
-        }
+        let distances = bfs(to, |location| self.backward_neighbours(*location));
 
-        if let Some((min_distance, from)) = min {
-            let mut path = Self::restore_path(&distance, from);
-            path.reverse();
-            Some((from, min_distance, path))
-        } else {
-            None
-        }
+        let (distance, from) = self
+            .hills
+            .iter()
+            .filter(|(_, location)| **location != to && location.height == 0)
+            .filter_map(|(_, location)| Some((distances.distance(location)?, *location)))
+            .min_by_key(|(distance, _)| *distance)?;
+
+        let mut path = distances.path_to(&from)?;
+        path.reverse();
+        Some((from, distance, path))
     }
 
     fn possible_neighbours<'a>(
         &'a self,
         location: Location,
     ) -> impl Iterator<Item = Location> + 'a {
-        [(-1isize, 0isize), (1, 0), (0, 1), (0, -1)]
-            .iter()
-            .filter_map(move |(d_row, d_column)| {
-                let row = match location.row.checked_add_signed(*d_row) {
-                    Some(row) if row < self.hills.column_len() => row,
-                    _ => return None,
-                };
-                let column = match location.column.checked_add_signed(*d_column) {
-                    Some(column) if column < self.hills.row_len() => column,
-                    _ => return None,
-                };
-                Some(self.hills[(row, column)])
-            })
+        self.hills
+            .neighbours_4(location.as_index())
+            .map(|position| self.hills[position])
     }
 
     fn neighbours<'a>(&'a self, location: Location) -> impl Iterator<Item = Location> + 'a {
@@ -176,33 +101,14 @@ impl HillsMap {
             .filter(move |neighbour| location.height <= neighbour.height + 1)
     }
 
-    fn restore_path(distance: &Array2D<Option<(usize, Location)>>, to: Location) -> Vec<Location> {
-        let mut current = to;
-        let mut path = vec![current];
-        while let Some((_, previous)) = distance[current.as_index()] {
-            if previous == current {
-                break;
-            }
-            path.push(previous);
-
-            current = previous;
-        }
-        path.reverse();
-        path
-    }
-
     pub fn render_path(&self, path: &[Location]) -> String {
-        let mut render = Array2D::filled_with('.', self.hills.column_len(), self.hills.row_len());
+        let mut render = Grid::filled('.', self.hills.height(), self.hills.width());
         for window in path.windows(2) {
-            let current = window[0];
-            let next = window[1];
-
-            match (current.column.cmp(&next.column), current.row.cmp(&next.row)) {
-                (Ordering::Greater, _) => render[current.as_index()] = '<',
-                (Ordering::Less, _) => render[current.as_index()] = '>',
-                (_, Ordering::Greater) => render[current.as_index()] = '^',
-                (_, Ordering::Less) => render[current.as_index()] = 'v',
-                _ => {}
+            let (current, next) = (window[0], window[1]);
+            if let Some(arrow) = Direction::from_delta(next.point - current.point)
+                .and_then(|direction| direction.arrow())
+            {
+                render[current.as_index()] = arrow;
             }
         }
 
@@ -210,10 +116,6 @@ impl HillsMap {
             render[last.as_index()] = 'E'
         }
 
-        render
-            .rows_iter()
-            .map(|row| String::from_iter(row))
-            .collect::<Vec<_>>()
-            .join("\n")
+        render.render(|c| *c)
     }
 }
@@ -0,0 +1,44 @@
+use crate::hills_map::{HillsMap, Location};
+use advent_util::solution::Solution;
+
+pub mod hills_map;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// The map with the start and the end locations.
+    type Input = (HillsMap, Location, Location);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(HillsMap::parse_map(input)?)
+    }
+
+    fn part_one(&self, (map, start, end): &Self::Input) -> Self::PartOne {
+        let (length, _) = map.find_path(*start, *end).expect("Cannot find path!");
+        length
+    }
+
+    fn part_two(&self, (map, _, end): &Self::Input) -> Self::PartTwo {
+        let (_, distance, _) = map
+            .find_shortest_path_from_lowest(*end)
+            .expect("Cannot find minimal start!");
+        distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day12;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day12, env!("CARGO_MANIFEST_DIR"));
+    }
+}
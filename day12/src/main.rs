@@ -1,27 +1,50 @@
-use crate::hills_map::HillsMap;
-use advent_util::read_input;
+use advent_util::output::OutputFormat;
+use advent_util::solution::{exit_with_error, exit_with_usage, run_with_args};
+use advent_util::viz::{Animation, Palette, VizTarget};
+use day12::hills_map::{HillsMap, Location};
+use day12::Day12;
 
-mod hills_map;
+/// Longer paths are sampled to keep the animation small.
+const MAX_FRAMES: usize = 200;
 
 fn main() {
-    let input = read_input(12).unwrap();
-    let (map, start, end) = HillsMap::parse_map(&input);
-    let path = map.find_path(start, end);
-
-    if let Some((length, path)) = path {
-        println!("Path length from S to E: {}", length);
-        println!("Path:");
-        println!("{}", map.render_path(&path));
-    } else {
-        println!("Error!! Cannot find path!")
+    let (args, target) = VizTarget::from_env(12);
+    if target == Some(VizTarget::Ansi) && args.format == OutputFormat::Json {
+        // frames in the terminal would be mixed with the records
+        exit_with_usage(
+            12,
+            VizTarget::USAGE,
+            "--viz ansi cannot be combined with --format json",
+        );
+    }
+    if let Some(target) = target {
+        let input = args.input.read().unwrap_or_else(|err| exit_with_error(err));
+        let (map, start, end) =
+            HillsMap::parse_map(&input).unwrap_or_else(|err| exit_with_error(err));
+        let (_, path) = map
+            .find_path(start, end)
+            .unwrap_or_else(|| exit_with_error("Cannot find path from S to E"));
+        visualize(&map, &path, &target);
     }
+    run_with_args(&Day12, &args);
+}
 
-    let min_hiking_start = map.find_shortest_path_from_lowest(end);
-    if let Some((from, distance, path)) = min_hiking_start {
-        println!("Path length from miminal S {:?} to E: {}", from, distance);
-        println!("Path:");
-        println!("{}", map.render_path(&path));
-    } else {
-        println!("Error! Cannot find minimal start!")
+/// Path from S growing a step per frame, written before the answers are printed.
+fn visualize(map: &HillsMap, path: &[Location], target: &VizTarget) {
+    let arrow = [230, 200, 60];
+    let palette = Palette::default()
+        .with('>', arrow)
+        .with('<', arrow)
+        .with('^', arrow)
+        .with('v', arrow)
+        .with('E', [230, 60, 60]);
+    let mut animation = Animation::new(palette);
+    let every = path.len().div_ceil(MAX_FRAMES).max(1);
+    // the last frame always shows the whole path
+    for end in (1..=path.len()).filter(|end| end.is_multiple_of(every) || *end == path.len()) {
+        animation.push_text(&map.render_path(&path[..end]));
     }
+    animation
+        .write(target)
+        .unwrap_or_else(|err| panic!("{}", err));
 }
@@ -0,0 +1,74 @@
+use crate::packets::PacketElement;
+use advent_util::solution::Solution;
+
+mod packets;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(PacketElement, PacketElement)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        input
+            .trim()
+            .split("\n\n")
+            .map(|pair| {
+                let (first, second) = pair
+                    .split_once('\n')
+                    .ok_or_else(|| format!("Expected pair of packets: {}", pair))?;
+                let parse_packet = |packet: &str| {
+                    packet
+                        .parse::<PacketElement>()
+                        .map_err(|err| format!("Cannot parse packet '{}': {:?}", packet, err))
+                };
+                Ok((parse_packet(first)?, parse_packet(second)?))
+            })
+            .collect()
+    }
+
+    fn part_one(&self, packets_pairs: &Self::Input) -> Self::PartOne {
+        packets_pairs
+            .iter()
+            .enumerate()
+            .map(|(i, (f, s))| (i, f < s))
+            .filter(|(_, result)| *result)
+            .map(|(i, _)| i + 1)
+            .sum()
+    }
+
+    fn part_two(&self, packets_pairs: &Self::Input) -> Self::PartTwo {
+        let mut packets = packets_pairs
+            .iter()
+            .flat_map(|(f, s)| [f, s])
+            .collect::<Vec<_>>();
+
+        let sep_2: PacketElement = "[[2]]".parse().unwrap();
+        let sep_6: PacketElement = "[[6]]".parse().unwrap();
+
+        packets.push(&sep_2);
+        packets.push(&sep_6);
+
+        packets.sort();
+
+        let sep_2_index = packets.binary_search(&&sep_2).unwrap() + 1;
+        let sep_6_index = packets.binary_search(&&sep_6).unwrap() + 1;
+        sep_2_index * sep_6_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day13;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day13, env!("CARGO_MANIFEST_DIR"));
+    }
+}
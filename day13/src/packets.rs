@@ -31,24 +31,24 @@ impl FromStr for PacketElement {
 
 impl PartialOrd for PacketElement {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (self, other) {
-            (Integer(i1), Integer(i2)) => i1.partial_cmp(i2),
-            (List(elements1), List(elements2)) => elements1.partial_cmp(elements2),
-            (Integer(i1), List(elements2)) => vec![Integer(*i1)].partial_cmp(elements2),
-            (List(elements1), Integer(i2)) => elements1.partial_cmp(&vec![Integer(*i2)]),
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for PacketElement {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        match (self, other) {
+            (Integer(i1), Integer(i2)) => i1.cmp(i2),
+            (List(elements1), List(elements2)) => elements1.cmp(elements2),
+            (Integer(i1), List(elements2)) => vec![Integer(*i1)].cmp(elements2),
+            (List(elements1), Integer(i2)) => elements1.cmp(&vec![Integer(*i2)]),
+        }
     }
 }
 
 fn parse_integer(input: &str) -> IResult<&str, PacketElement> {
     map_res(recognize(many1(one_of("0123456789"))), |s: &str| {
-        s.parse::<u32>().map(|i| Integer(i))
+        s.parse::<u32>().map(Integer)
     })(input)
 }
 
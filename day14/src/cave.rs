@@ -1,559 +1,197 @@
-use array2d::Array2D;
-use bevy::math::{vec2, vec3};
-use bevy::prelude::shape::Quad;
-use bevy::prelude::{
-    info, Assets, Color, ColorMaterial, Commands, Component, Entity, Handle, Mesh, Mut, Query, Res,
-    ResMut, Resource, Time, Transform, With, Without,
-};
-use bevy::sprite::MaterialMesh2dBundle;
-use std::collections::BTreeMap;
+use advent_util::parse::{lines, separated, ParseError};
+use advent_util::point::{Direction, Point2};
+use advent_util::sparse_grid::{Bounds, SparseGrid};
+#[cfg(feature = "render")]
+use bevy::prelude::Component;
 use std::str::FromStr;
 
 #[derive(Debug)]
 struct Wall {
-    parts_path: Vec<(isize, isize)>,
+    parts_path: Vec<Point2>,
 }
 
 impl FromStr for Wall {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts_path = s
-            .split(" -> ")
-            .map(|coord| coord.split_once(",").unwrap())
-            .map(|(x, y)| (x.parse::<isize>().unwrap(), y.parse::<isize>().unwrap()))
-            .collect::<Vec<_>>();
+        let parts_path = separated(s, " -> ", str::parse)?;
         Ok(Wall { parts_path })
     }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-enum CaveChunk {
-    Air,
+pub(crate) enum CaveChunk {
     Wall,
     Sand,
 }
 
 impl CaveChunk {
-    fn render(&self) -> char {
-        match self {
-            CaveChunk::Air => '.',
-            CaveChunk::Wall => '#',
-            CaveChunk::Sand => 'o',
+    fn render(chunk: Option<&Self>) -> char {
+        match chunk {
+            None => '.',
+            Some(CaveChunk::Wall) => '#',
+            Some(CaveChunk::Sand) => 'o',
         }
     }
 }
 
-#[derive(Debug, Component)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "render", derive(Component))]
 pub struct Cave {
-    visible_rect: Array2D<CaveChunk>,
-    rect_x_shift: usize,
-    filled_with_sand: bool,
-    additional_chunks: BTreeMap<usize, BTreeMap<usize, CaveChunk>>,
+    /// Walls and sand by their points in the input.
+    chunks: SparseGrid<CaveChunk>,
+    pub(crate) wall_bounds: Bounds,
+    /// Sand falls into the abyss below the lowest wall, until the floor is placed two rows below it.
+    pub(crate) filled_with_sand: bool,
 }
 
-const SIZE: f32 = 3.;
-
 impl Cave {
     pub fn render(&self) -> String {
-        self.visible_rect
-            .rows_iter()
-            .map(|row| String::from_iter(row.map(|chunk| chunk.render())))
-            .collect::<Vec<_>>()
-            .join("\n")
+        self.render_within(self.bounds())
     }
 
-    fn table_coord_to_world(&self, (row, column): (usize, usize)) -> (f32, f32) {
-        let x_shift = self.visible_rect.row_len() as f32 / 2.;
-        let y_shift = self.visible_rect.column_len() as f32 / 2.;
+    /// Like [Cave::render], but of the given area, e.g. to keep the same size while sand falls.
+    pub fn render_within(&self, bounds: Bounds) -> String {
+        self.chunks.render_within(bounds, CaveChunk::render)
+    }
 
-        (
-            (column as f32 - self.rect_x_shift as f32 - x_shift) * SIZE,
-            (y_shift - row as f32) * SIZE,
-        )
+    /// Bounds of walls and sand.
+    pub fn bounds(&self) -> Bounds {
+        self.chunks.bounds().unwrap_or(self.wall_bounds)
     }
 
-    fn move_sand(&mut self, sand: &mut MovingSand) -> MoveStatus {
-        if !self.filled_with_sand {
-            for option in sand.move_options() {
-                if let Some((row, column)) = option.make_step() {
-                    if !self.inside_rect((row, column)) {
-                        return MoveStatus::Out;
-                    }
-                    if self.visible_rect[(row, column - self.rect_x_shift)] == CaveChunk::Air {
-                        sand.row = row;
-                        sand.column = column;
-                        return MoveStatus::Success;
-                    }
-                } else {
-                    return MoveStatus::Out;
-                }
-            }
-            self.visible_rect[(sand.row, sand.column - self.rect_x_shift)] = CaveChunk::Sand;
+    #[cfg(feature = "render")]
+    pub(crate) fn walls(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.chunks
+            .iter()
+            .filter(|(_, chunk)| **chunk == CaveChunk::Wall)
+            .map(|(point, _)| point)
+    }
 
-            MoveStatus::Stop
-        } else {
-            for option in sand.move_options() {
-                if let Some((row, column)) = option.make_step() {
-                    if self.inside_rect((row, column)) {
-                        if self.visible_rect[(row, column - self.rect_x_shift)] == CaveChunk::Air {
-                            sand.row = row;
-                            sand.column = column;
-                            return MoveStatus::Success;
-                        }
-                    } else {
-                        if *self
-                            .additional_chunks
-                            .get(&row)
-                            .and_then(|row| row.get(&column))
-                            .unwrap_or(&CaveChunk::Air)
-                            == CaveChunk::Air
-                        {
-                            if row != self.visible_rect.column_len() + 1 {
-                                sand.row = row;
-                                sand.column = column;
-                                return MoveStatus::Success;
-                            }
-                        }
-                    }
-                }
+    pub(crate) fn move_sand(&mut self, sand: &mut MovingSand) -> MoveStatus {
+        let lowest_wall = self.wall_bounds.max.y;
+        for direction in [Direction::Down, Direction::DownLeft, Direction::DownRight] {
+            let next = sand.point.step(direction);
+            if !self.filled_with_sand && next.y > lowest_wall {
+                return MoveStatus::Out;
             }
-
-            if self.inside_rect((sand.row, sand.column)) {
-                self.visible_rect[(sand.row, sand.column - self.rect_x_shift)] = CaveChunk::Sand;
-            } else {
-                if let Some(map) = self.additional_chunks.get_mut(&sand.row) {
-                    map.insert(sand.column, CaveChunk::Sand);
-                } else {
-                    self.additional_chunks
-                        .insert(sand.row, BTreeMap::from([(sand.column, CaveChunk::Sand)]));
-                }
+            if self.is_free(next, lowest_wall + 2) {
+                sand.point = next;
+                return MoveStatus::Success;
             }
-
-            MoveStatus::Stop
         }
-    }
+        self.chunks.insert(sand.point, CaveChunk::Sand);
 
-    fn inside_rect(&self, (row, column): (usize, usize)) -> bool {
-        (0..self.visible_rect.column_len()).contains(&row)
-            && (self.rect_x_shift..(self.visible_rect.row_len() + self.rect_x_shift))
-                .contains(&column)
+        MoveStatus::Stop
     }
 
-    pub fn sand_count(&self) -> usize {
-        self.visible_rect
-            .rows_iter()
-            .map(|row| row.filter(|chunk| **chunk == CaveChunk::Sand).count())
-            .sum()
+    fn is_free(&self, point: Point2, floor: isize) -> bool {
+        (!self.filled_with_sand || point.y < floor) && !self.chunks.contains(point)
     }
 
-    pub fn sand_total(&self) -> usize {
-        let sand_in_additional: usize = self
-            .additional_chunks
+    pub fn sand_count(&self) -> usize {
+        self.chunks
             .iter()
-            .map(|(_, row)| {
-                row.iter()
-                    .filter(|(_, chunk)| **chunk == CaveChunk::Sand)
-                    .count()
-            })
-            .sum();
-        self.sand_count() + sand_in_additional
+            .filter(|(_, chunk)| **chunk == CaveChunk::Sand)
+            .count()
     }
 
     pub fn fill_with_sand(&mut self) {
+        self.fill_with_sand_observed(|_| {});
+    }
+
+    /// Fills the cave like [Cave::fill_with_sand] and calls `observe` after every unit of sand
+    /// comes to rest.
+    pub fn fill_with_sand_observed(&mut self, mut observe: impl FnMut(&Self)) {
         if self.filled_with_sand {
             return;
         }
-        'outer: loop {
-            let mut sand = MovingSand::default();
-            loop {
-                match self.move_sand(&mut sand) {
-                    MoveStatus::Out => {
-                        self.filled_with_sand = true;
-                        break 'outer;
-                    }
-                    MoveStatus::Stop => {
-                        break;
-                    }
-                    MoveStatus::Success => {}
-                }
-            }
+        while self.drop_sand().is_some() {
+            observe(self);
         }
+        self.filled_with_sand = true;
     }
 
     pub fn fill_completely(&mut self) {
+        self.fill_completely_observed(|_| {});
+    }
+
+    /// Fills the cave like [Cave::fill_completely] and calls `observe` after every unit of sand
+    /// comes to rest.
+    pub fn fill_completely_observed(&mut self, mut observe: impl FnMut(&Self)) {
         if !self.filled_with_sand {
             return;
         }
+        while let Some(point) = self.drop_sand() {
+            observe(self);
+            if point == SAND_SOURCE {
+                break;
+            }
+        }
+    }
 
-        'outer: loop {
-            let mut sand = MovingSand::default();
-            loop {
-                match self.move_sand(&mut sand) {
-                    MoveStatus::Out => {
-                        break 'outer;
-                    }
-                    MoveStatus::Stop => {
-                        if sand.row == 0 && sand.column == 500 {
-                            break 'outer;
-                        }
-                        break;
-                    }
-                    MoveStatus::Success => {}
-                }
+    /// Point where a new unit of sand comes to rest, `None` if it falls into the abyss.
+    pub(crate) fn drop_sand(&mut self) -> Option<Point2> {
+        let mut sand = MovingSand::default();
+        loop {
+            match self.move_sand(&mut sand) {
+                MoveStatus::Out => return None,
+                MoveStatus::Stop => return Some(sand.point),
+                MoveStatus::Success => {}
             }
         }
     }
 }
 
 impl FromStr for Cave {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let walls = s
-            .trim()
-            .split("\n")
-            .map(|line| line.parse::<Wall>().unwrap())
-            .collect::<Vec<_>>();
-
-        let mut min_x = None;
-        let mut max_x = None;
-        let mut max_y = None;
+        let walls = lines(s.trim_end(), str::parse::<Wall>)?;
 
+        let mut chunks = SparseGrid::new();
         for wall in walls.iter() {
-            for (x, y) in wall.parts_path.iter() {
-                if let Some(min_x_value) = min_x {
-                    if min_x_value > x {
-                        min_x = Some(x);
-                    }
-                } else {
-                    min_x = Some(x);
+            for window in wall.parts_path.windows(2) {
+                let (from, to) = (window[0], window[1]);
+                let step = (to - from).signum();
+
+                let mut current = from;
+                chunks.insert(current, CaveChunk::Wall);
+                while current != to {
+                    current += step;
+                    chunks.insert(current, CaveChunk::Wall);
                 }
-
-                if let Some(max_x_value) = max_x {
-                    if max_x_value < x {
-                        max_x = Some(x);
-                    }
-                } else {
-                    max_x = Some(x);
-                }
-
-                if let Some(max_y_value) = max_y {
-                    if max_y_value < y {
-                        max_y = Some(y);
-                    }
-                } else {
-                    max_y = Some(y);
-                }
-            }
-        }
-
-        let max_x = *max_x.unwrap() as usize;
-        let max_y = *max_y.unwrap() as usize;
-        let min_x = *min_x.unwrap() as usize;
-
-        let mut visible_rect = Array2D::filled_with(CaveChunk::Air, max_y + 1, max_x - min_x + 1);
-
-        for wall in walls.iter() {
-            for window in wall.parts_path.as_slice().windows(2) {
-                let (from_x, from_y) = window[0];
-                let (to_x, to_y) = window[1];
-                let d_x = (to_x - from_x).signum();
-                let d_y = (to_y - from_y).signum();
-
-                let mut current_x = from_x;
-                let mut current_y = from_y;
-
-                while current_x != to_x || current_y != to_y {
-                    let coord_x = current_x as usize - min_x;
-                    let coord_y = current_y as usize;
-                    visible_rect[(coord_y, coord_x)] = CaveChunk::Wall;
-
-                    current_x += d_x;
-                    current_y += d_y;
-                }
-
-                let coord_x = to_x as usize - min_x;
-                let coord_y = to_y as usize;
-                visible_rect[(coord_y, coord_x)] = CaveChunk::Wall;
             }
         }
 
+        let wall_bounds = chunks
+            .bounds()
+            .ok_or_else(|| ParseError::new("Expected at least one wall", s))?;
         Ok(Cave {
-            visible_rect,
-            rect_x_shift: min_x,
+            chunks,
+            wall_bounds,
             filled_with_sand: false,
-            additional_chunks: BTreeMap::new(),
         })
     }
 }
 
-pub fn render_cave(
-    mut commands: Commands,
-    caves: Query<&Cave>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-) {
-    let mesh = meshes.add(Mesh::from(Quad {
-        size: vec2(SIZE, SIZE),
-        flip: false,
-    }));
-    let material = materials.add(Color::rgb(0.9, 0.4, 0.4).into());
-
-    for cave in caves.iter() {
-        info!("rendering cave");
-
-        for (row_index, row) in cave.visible_rect.rows_iter().enumerate() {
-            for (column_index, chunk) in row.enumerate() {
-                let (x, y) =
-                    cave.table_coord_to_world((row_index, column_index + cave.rect_x_shift));
-                if let CaveChunk::Wall = chunk {
-                    commands.spawn(MaterialMesh2dBundle {
-                        transform: Transform::from_translation(vec3(x, y, 0.)),
-                        mesh: mesh.clone().into(),
-                        material: material.clone(),
-                        ..Default::default()
-                    });
-                }
-            }
-        }
-    }
-}
+/// Point where the sand comes into the cave from.
+pub(crate) const SAND_SOURCE: Point2 = Point2::new(500, 0);
 
-#[derive(Component, Debug)]
+#[derive(Debug)]
+#[cfg_attr(feature = "render", derive(Component))]
 pub struct MovingSand {
-    row: usize,
-    column: usize,
+    pub(crate) point: Point2,
 }
 
 impl Default for MovingSand {
     fn default() -> Self {
-        Self {
-            row: 0,
-            column: 500,
-        }
+        Self { point: SAND_SOURCE }
     }
 }
 
-#[derive(Clone, Copy)]
-enum MoveOptions {
-    Down((usize, usize)),
-    Left((usize, usize)),
-    Right((usize, usize)),
-}
-
-enum MoveStatus {
+pub(crate) enum MoveStatus {
     Out,
     Stop,
     Success,
 }
-
-impl MovingSand {
-    fn move_options(&self) -> impl Iterator<Item = MoveOptions> {
-        [
-            MoveOptions::Down((self.row, self.column)),
-            MoveOptions::Left((self.row, self.column)),
-            MoveOptions::Right((self.row, self.column)),
-        ]
-        .into_iter()
-    }
-}
-
-impl MoveOptions {
-    fn make_step(&self) -> Option<(usize, usize)> {
-        match self {
-            MoveOptions::Down((row, column)) => Some((*row + 1, *column)),
-            MoveOptions::Left((row, column)) => column
-                .checked_add_signed(-1)
-                .map(|column| (*row + 1, column)),
-            MoveOptions::Right((row, column)) => Some((*row + 1, *column + 1)),
-        }
-    }
-}
-
-#[derive(Resource)]
-pub struct CaveStatistics {
-    pub without_floor: usize,
-    pub at_all: usize,
-}
-
-impl CaveStatistics {
-    pub fn new() -> Self {
-        Self {
-            without_floor: 0,
-            at_all: 0,
-        }
-    }
-}
-
-#[derive(Component)]
-pub struct NeedsToBeFilled;
-
-#[derive(Component)]
-pub struct FilledCave;
-
-#[derive(Resource, Default)]
-pub struct CaveCache {
-    mesh: Option<Handle<Mesh>>,
-    sand_material: Option<Handle<ColorMaterial>>,
-}
-
-impl CaveCache {
-    fn get_mesh<F>(&mut self, mesh_provider: F) -> Handle<Mesh>
-    where
-        F: FnOnce() -> Handle<Mesh>,
-    {
-        if let Some(mesh) = &self.mesh {
-            mesh.clone()
-        } else {
-            let mesh = mesh_provider();
-            self.mesh = Some(mesh.clone());
-            mesh.clone()
-        }
-    }
-
-    fn get_sand_material<F>(&mut self, material_provider: F) -> Handle<ColorMaterial>
-    where
-        F: FnOnce() -> Handle<ColorMaterial>,
-    {
-        if let Some(material) = &self.sand_material {
-            material.clone()
-        } else {
-            let material = material_provider();
-            self.sand_material = Some(material.clone());
-            material.clone()
-        }
-    }
-}
-
-pub fn move_sand(
-    mut commands: Commands,
-    mut caves: Query<(Entity, &mut Cave), Without<NeedsToBeFilled>>,
-    mut moving_sand: Query<(Entity, &mut MovingSand, &mut Transform)>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    mut cave_statistics: ResMut<CaveStatistics>,
-    mut cave_cache: ResMut<CaveCache>,
-) {
-    for (cave_entity, mut cave) in caves.iter_mut() {
-        let mut any = false;
-        let mut need_new_sand = false;
-        for (entity, mut sand, mut transform) in moving_sand.iter_mut() {
-            any = true;
-            match cave.move_sand(&mut sand) {
-                MoveStatus::Out => {
-                    commands.entity(entity).despawn();
-
-                    cave_statistics.without_floor = cave.sand_count();
-                    info!("Cannot add new sand!");
-                    info!("Sand count: {}", cave_statistics.without_floor);
-                    cave.filled_with_sand = true;
-
-                    commands.entity(cave_entity).insert(NeedsToBeFilled);
-                }
-                MoveStatus::Stop => {
-                    commands.entity(entity).remove::<MovingSand>();
-                    need_new_sand = true;
-                }
-                MoveStatus::Success => {
-                    let (x, y) = cave.table_coord_to_world((sand.row, sand.column));
-                    transform.translation = vec3(x, y, SIZE);
-                }
-            }
-        }
-        if !any || need_new_sand {
-            spawn_sand(
-                &mut commands,
-                &mut meshes,
-                &mut materials,
-                &mut cave,
-                &mut cave_cache,
-                (0, 500),
-            );
-        }
-    }
-}
-
-const MOVE_SAND_AT_ONCE: usize = 1000;
-
-pub fn fill_cave_with_sand_completely(
-    time: Res<Time>,
-    mut commands: Commands,
-    mut caves: Query<(Entity, &mut Cave), (With<NeedsToBeFilled>, Without<FilledCave>)>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    mut cave_statistics: ResMut<CaveStatistics>,
-    mut cave_cache: ResMut<CaveCache>,
-) {
-    let amount_to_check = (time.delta_seconds() * MOVE_SAND_AT_ONCE as f32).ceil() as usize;
-    for (entity, mut cave) in caves.iter_mut() {
-        'outer: for _ in 0..amount_to_check {
-            let mut sand = MovingSand::default();
-            loop {
-                match cave.move_sand(&mut sand) {
-                    MoveStatus::Out => {
-                        break 'outer;
-                    }
-                    MoveStatus::Stop => {
-                        if sand.row == 0 && sand.column == 500 {
-                            commands.entity(entity).insert(FilledCave);
-
-                            spawn_sand(
-                                &mut commands,
-                                &mut meshes,
-                                &mut materials,
-                                &mut cave,
-                                &mut cave_cache,
-                                (sand.row, sand.column),
-                            );
-
-                            cave_statistics.at_all = cave.sand_total();
-                            info!("Cannot add new sand at all");
-                            info!("Sand count: {}", cave_statistics.at_all);
-
-                            break 'outer;
-                        }
-                        break;
-                    }
-                    MoveStatus::Success => {}
-                }
-            }
-            spawn_sand(
-                &mut commands,
-                &mut meshes,
-                &mut materials,
-                &mut cave,
-                &mut cave_cache,
-                (sand.row, sand.column),
-            );
-        }
-    }
-}
-
-fn spawn_sand(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-    cave: &mut Mut<Cave>,
-    cave_cache: &mut ResMut<CaveCache>,
-    (row, column): (usize, usize),
-) {
-    let (x, y) = cave.table_coord_to_world((row, column));
-    commands.spawn((
-        MaterialMesh2dBundle {
-            mesh: cave_cache
-                .get_mesh(|| {
-                    meshes.add(Mesh::from(Quad {
-                        size: vec2(SIZE, SIZE),
-                        flip: false,
-                    }))
-                })
-                .into(),
-            material: cave_cache
-                .get_sand_material(|| materials.add(Color::rgb(0.4, 0.9, 0.4).into())),
-            transform: Transform::from_translation(vec3(x, y, SIZE)),
-            ..Default::default()
-        },
-        MovingSand::default(),
-    ));
-}
@@ -0,0 +1,47 @@
+use crate::cave::Cave;
+use advent_util::solution::Solution;
+
+pub mod cave;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod simulation;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, cave: &Self::Input) -> Self::PartOne {
+        let mut cave = cave.clone();
+        cave.fill_with_sand();
+        cave.sand_count()
+    }
+
+    fn part_two(&self, cave: &Self::Input) -> Self::PartTwo {
+        let mut cave = cave.clone();
+        cave.fill_with_sand();
+        cave.fill_completely();
+        cave.sand_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day14;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day14, env!("CARGO_MANIFEST_DIR"));
+    }
+}
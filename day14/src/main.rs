@@ -1,121 +1,77 @@
-use crate::cave::{
-    fill_cave_with_sand_completely, move_sand, render_cave, Cave, CaveCache, CaveStatistics,
-};
-use advent_util::read_input;
-use bevy::prelude::{
-    App, Camera2dBundle, Commands, CoreStage, Msaa, Plugin, PluginGroup, Query, ResMut,
-    StartupStage, SystemStage, WindowDescriptor, WindowPlugin,
-};
-use bevy::time::FixedTimestep;
-use bevy::DefaultPlugins;
+use advent_util::solution::{run_with_args, MainArgs, Solution};
+use advent_util::tui;
+use advent_util::viz::{Animation, Palette, VizTarget};
+use day14::cave::Cave;
+use day14::simulation::CaveSimulation;
+use day14::Day14;
 use std::env;
 
-mod cave;
+/// Sand is sampled to keep the animation small.
+const MAX_FRAMES: usize = 200;
 
 fn main() {
-    App::new()
-        .add_startup_system(read_cave)
-        .add_plugin(CavePlugin)
-        .run();
-}
-
-struct CavePlugin;
-
-impl Plugin for CavePlugin {
-    fn build(&self, app: &mut App) {
-        if env::args().find(|arg| arg == "--render").is_some() {
-            app.insert_resource(Msaa { samples: 4 })
-                .add_plugins(DefaultPlugins.set(WindowPlugin {
-                    window: WindowDescriptor {
-                        title: "Caves".to_string(),
-                        width: 800.,
-                        height: 600.,
-                        ..Default::default()
-                    },
-                    ..Default::default()
-                }))
-                .add_startup_system(setup_camera)
-                .add_startup_system_to_stage(StartupStage::PostStartup, render_cave)
-                .add_stage_after(
-                    CoreStage::Update,
-                    "fixed_update",
-                    SystemStage::parallel()
-                        .with_run_criteria(FixedTimestep::step(0.0001))
-                        .with_system(move_sand),
-                )
-                .add_system(fill_cave_with_sand_completely)
-                // .add_plugin(FrameTimeDiagnosticsPlugin::default())
-                // .add_plugin(LogDiagnosticsPlugin::default())
-                // .add_plugin(EntityCountDiagnosticsPlugin::default())
-                .insert_resource(CaveStatistics::new())
-                .insert_resource(CaveCache::default());
-        } else {
-            app.insert_resource(CaveStatistics::new())
-                .add_stage(
-                    "Begin",
-                    SystemStage::single_threaded().with_system(debug_println_cave),
-                )
-                .add_stage_after(
-                    "Begin",
-                    "Fill",
-                    SystemStage::single_threaded().with_system(fill_with_sand),
-                )
-                .add_stage_after(
-                    "Fill",
-                    "Result Print",
-                    SystemStage::single_threaded().with_system(debug_println_cave),
-                )
-                .add_stage_after(
-                    "Result Print",
-                    "Fill Completely",
-                    SystemStage::single_threaded().with_system(fill_with_sand_completely),
-                )
-                .add_stage_after(
-                    "Fill Completely",
-                    "Result Result print",
-                    SystemStage::single_threaded().with_system(debug_println_cave),
-                );
-        }
+    if env::args().any(|arg| arg == "--render") {
+        render();
+        return;
+    }
+    if let Some(args) = tui::from_env(14) {
+        explore(&args);
+        return;
+    }
+    let (args, target) = VizTarget::from_env(14);
+    match target {
+        Some(target) => visualize(&args, &target),
+        None => run_with_args(&Day14, &args),
     }
 }
 
-fn setup_camera(mut commands: Commands) {
-    commands.spawn(Camera2dBundle::default());
+#[cfg(feature = "render")]
+fn render() {
+    day14::render::run();
 }
 
-fn read_cave(mut commands: Commands) {
-    let cave: Cave = read_input(14).unwrap().parse().unwrap();
-    commands.spawn(cave);
+#[cfg(not(feature = "render"))]
+fn render() {
+    eprintln!("day14 is built without 'render' feature, rebuild it with '--features render'");
 }
 
-fn debug_println_cave(query: Query<&Cave>) {
-    for cave in query.iter() {
-        println!("Rendered cave:\n{}", cave.render());
-    }
+fn explore(args: &MainArgs) {
+    let input = args.input.read().unwrap_or_else(|err| panic!("{}", err));
+    let cave = Day14.parse(&input).unwrap_or_else(|err| panic!("{}", err));
+    tui::explore("Cave", CaveSimulation::new(cave))
+        .unwrap_or_else(|err| panic!("Terminal error: {}", err));
 }
 
-fn fill_with_sand(mut caves: Query<&mut Cave>, mut cave_statistics: ResMut<CaveStatistics>) {
-    for mut cave in caves.iter_mut() {
-        cave.fill_with_sand();
-
-        cave_statistics.without_floor = cave.sand_count();
-        println!("Sand count: {}", cave_statistics.without_floor);
-    }
-}
+/// Sand falling without the floor and then with it.
+fn visualize(args: &MainArgs, target: &VizTarget) {
+    let input = args.input.read().unwrap_or_else(|err| panic!("{}", err));
+    let mut cave = Day14.parse(&input).unwrap_or_else(|err| panic!("{}", err));
 
-fn fill_with_sand_completely(
-    mut caves: Query<&mut Cave>,
-    mut cave_statistics: ResMut<CaveStatistics>,
-) {
-    for mut cave in caves.iter_mut() {
-        cave.fill_completely();
+    // the filled cave gives the area of all frames, so the walls stay in place
+    let mut filled = cave.clone();
+    let mut grains = 0usize;
+    filled.fill_with_sand_observed(|_| grains += 1);
+    filled.fill_completely_observed(|_| grains += 1);
+    let bounds = filled.bounds();
+    let every = grains.div_ceil(MAX_FRAMES).max(1);
 
-        cave_statistics.at_all = cave.sand_total();
+    let palette = Palette::default()
+        .with('#', [140, 110, 90])
+        .with('o', [230, 200, 90]);
+    let mut animation = Animation::new(palette).scale(2);
+    animation.push_text(&cave.render_within(bounds));
+    let mut fallen = 0usize;
+    let mut observe = |cave: &Cave| {
+        fallen += 1;
+        if fallen.is_multiple_of(every) {
+            animation.push_text(&cave.render_within(bounds));
+        }
+    };
+    cave.fill_with_sand_observed(&mut observe);
+    cave.fill_completely_observed(&mut observe);
+    animation.push_text(&cave.render_within(bounds));
 
-        println!("Sand count: {}", cave_statistics.at_all);
-        println!(
-            "New sand: {}",
-            cave_statistics.at_all - cave_statistics.without_floor
-        );
-    }
+    animation
+        .write(target)
+        .unwrap_or_else(|err| panic!("{}", err));
 }
@@ -0,0 +1,290 @@
+use crate::cave::{Cave, MoveStatus, MovingSand, SAND_SOURCE};
+use advent_util::point::Point2;
+use advent_util::read_input;
+use bevy::math::{vec2, vec3};
+use bevy::prelude::shape::Quad;
+use bevy::prelude::{
+    info, App, Assets, Camera2dBundle, Color, ColorMaterial, Commands, Component, CoreStage,
+    Entity, Handle, Mesh, Msaa, Mut, Plugin, PluginGroup, Query, Res, ResMut, Resource,
+    StartupStage, SystemStage, Time, Transform, WindowDescriptor, WindowPlugin, With, Without,
+};
+use bevy::sprite::MaterialMesh2dBundle;
+use bevy::time::FixedTimestep;
+use bevy::DefaultPlugins;
+
+const SIZE: f32 = 3.;
+
+/// Runs the window with animated sand falling into the cave from `day14/input.txt`.
+pub fn run() {
+    App::new()
+        .add_startup_system(read_cave)
+        .add_plugin(CavePlugin)
+        .run();
+}
+
+struct CavePlugin;
+
+impl Plugin for CavePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Msaa { samples: 4 })
+            .add_plugins(DefaultPlugins.set(WindowPlugin {
+                window: WindowDescriptor {
+                    title: "Caves".to_string(),
+                    width: 800.,
+                    height: 600.,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }))
+            .add_startup_system(setup_camera)
+            .add_startup_system_to_stage(StartupStage::PostStartup, render_cave)
+            .add_stage_after(
+                CoreStage::Update,
+                "fixed_update",
+                SystemStage::parallel()
+                    .with_run_criteria(FixedTimestep::step(0.0001))
+                    .with_system(move_sand),
+            )
+            .add_system(fill_cave_with_sand_completely)
+            // .add_plugin(FrameTimeDiagnosticsPlugin::default())
+            // .add_plugin(LogDiagnosticsPlugin::default())
+            // .add_plugin(EntityCountDiagnosticsPlugin::default())
+            .insert_resource(CaveStatistics::new())
+            .insert_resource(CaveCache::default());
+    }
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn read_cave(mut commands: Commands) {
+    let cave: Cave = read_input(14).unwrap().parse().unwrap();
+    commands.spawn(cave);
+}
+
+fn table_coord_to_world(cave: &Cave, point: Point2) -> (f32, f32) {
+    let bounds = cave.wall_bounds;
+    let x_shift = bounds.width() as f32 / 2.;
+    let y_shift = (bounds.max.y + 1) as f32 / 2.;
+
+    (
+        (point.x as f32 - bounds.min.x as f32 - x_shift) * SIZE,
+        (y_shift - point.y as f32) * SIZE,
+    )
+}
+
+fn render_cave(
+    mut commands: Commands,
+    caves: Query<&Cave>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let mesh = meshes.add(Mesh::from(Quad {
+        size: vec2(SIZE, SIZE),
+        flip: false,
+    }));
+    let material = materials.add(Color::rgb(0.9, 0.4, 0.4).into());
+
+    for cave in caves.iter() {
+        info!("rendering cave");
+
+        for wall in cave.walls() {
+            let (x, y) = table_coord_to_world(cave, wall);
+            commands.spawn(MaterialMesh2dBundle {
+                transform: Transform::from_translation(vec3(x, y, 0.)),
+                mesh: mesh.clone().into(),
+                material: material.clone(),
+                ..Default::default()
+            });
+        }
+    }
+}
+
+#[derive(Resource)]
+struct CaveStatistics {
+    without_floor: usize,
+    at_all: usize,
+}
+
+impl CaveStatistics {
+    fn new() -> Self {
+        Self {
+            without_floor: 0,
+            at_all: 0,
+        }
+    }
+}
+
+#[derive(Component)]
+struct NeedsToBeFilled;
+
+#[derive(Component)]
+struct FilledCave;
+
+#[derive(Resource, Default)]
+struct CaveCache {
+    mesh: Option<Handle<Mesh>>,
+    sand_material: Option<Handle<ColorMaterial>>,
+}
+
+impl CaveCache {
+    fn get_mesh<F>(&mut self, mesh_provider: F) -> Handle<Mesh>
+    where
+        F: FnOnce() -> Handle<Mesh>,
+    {
+        if let Some(mesh) = &self.mesh {
+            mesh.clone()
+        } else {
+            let mesh = mesh_provider();
+            self.mesh = Some(mesh.clone());
+            mesh.clone()
+        }
+    }
+
+    fn get_sand_material<F>(&mut self, material_provider: F) -> Handle<ColorMaterial>
+    where
+        F: FnOnce() -> Handle<ColorMaterial>,
+    {
+        if let Some(material) = &self.sand_material {
+            material.clone()
+        } else {
+            let material = material_provider();
+            self.sand_material = Some(material.clone());
+            material.clone()
+        }
+    }
+}
+
+fn move_sand(
+    mut commands: Commands,
+    mut caves: Query<(Entity, &mut Cave), Without<NeedsToBeFilled>>,
+    mut moving_sand: Query<(Entity, &mut MovingSand, &mut Transform)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut cave_statistics: ResMut<CaveStatistics>,
+    mut cave_cache: ResMut<CaveCache>,
+) {
+    for (cave_entity, mut cave) in caves.iter_mut() {
+        let mut any = false;
+        let mut need_new_sand = false;
+        for (entity, mut sand, mut transform) in moving_sand.iter_mut() {
+            any = true;
+            match cave.move_sand(&mut sand) {
+                MoveStatus::Out => {
+                    commands.entity(entity).despawn();
+
+                    cave_statistics.without_floor = cave.sand_count();
+                    info!("Cannot add new sand!");
+                    info!("Sand count: {}", cave_statistics.without_floor);
+                    cave.filled_with_sand = true;
+
+                    commands.entity(cave_entity).insert(NeedsToBeFilled);
+                }
+                MoveStatus::Stop => {
+                    commands.entity(entity).remove::<MovingSand>();
+                    need_new_sand = true;
+                }
+                MoveStatus::Success => {
+                    let (x, y) = table_coord_to_world(&cave, sand.point);
+                    transform.translation = vec3(x, y, SIZE);
+                }
+            }
+        }
+        if !any || need_new_sand {
+            spawn_sand(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &mut cave,
+                &mut cave_cache,
+                SAND_SOURCE,
+            );
+        }
+    }
+}
+
+const MOVE_SAND_AT_ONCE: usize = 1000;
+
+#[allow(clippy::type_complexity)]
+fn fill_cave_with_sand_completely(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut caves: Query<(Entity, &mut Cave), (With<NeedsToBeFilled>, Without<FilledCave>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut cave_statistics: ResMut<CaveStatistics>,
+    mut cave_cache: ResMut<CaveCache>,
+) {
+    let amount_to_check = (time.delta_seconds() * MOVE_SAND_AT_ONCE as f32).ceil() as usize;
+    for (entity, mut cave) in caves.iter_mut() {
+        'outer: for _ in 0..amount_to_check {
+            let mut sand = MovingSand::default();
+            loop {
+                match cave.move_sand(&mut sand) {
+                    MoveStatus::Out => {
+                        break 'outer;
+                    }
+                    MoveStatus::Stop => {
+                        if sand.point == SAND_SOURCE {
+                            commands.entity(entity).insert(FilledCave);
+
+                            spawn_sand(
+                                &mut commands,
+                                &mut meshes,
+                                &mut materials,
+                                &mut cave,
+                                &mut cave_cache,
+                                sand.point,
+                            );
+
+                            cave_statistics.at_all = cave.sand_count();
+                            info!("Cannot add new sand at all");
+                            info!("Sand count: {}", cave_statistics.at_all);
+
+                            break 'outer;
+                        }
+                        break;
+                    }
+                    MoveStatus::Success => {}
+                }
+            }
+            spawn_sand(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &mut cave,
+                &mut cave_cache,
+                sand.point,
+            );
+        }
+    }
+}
+
+fn spawn_sand(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    cave: &mut Mut<Cave>,
+    cave_cache: &mut ResMut<CaveCache>,
+    point: Point2,
+) {
+    let (x, y) = table_coord_to_world(cave, point);
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: cave_cache
+                .get_mesh(|| {
+                    meshes.add(Mesh::from(Quad {
+                        size: vec2(SIZE, SIZE),
+                        flip: false,
+                    }))
+                })
+                .into(),
+            material: cave_cache
+                .get_sand_material(|| materials.add(Color::rgb(0.4, 0.9, 0.4).into())),
+            transform: Transform::from_translation(vec3(x, y, SIZE)),
+            ..Default::default()
+        },
+        MovingSand::default(),
+    ));
+}
@@ -0,0 +1,96 @@
+use crate::cave::{Cave, SAND_SOURCE};
+use advent_util::point::Point2;
+use advent_util::sparse_grid::Bounds;
+use advent_util::tui::Simulation;
+
+/// Sand falling a unit per step, first into the abyss and then onto the floor.
+#[derive(Debug, Clone)]
+pub struct CaveSimulation {
+    cave: Cave,
+    /// Area of the completely filled cave, so the walls stay in place.
+    bounds: Bounds,
+    sand: usize,
+    /// Sand at rest when the first unit fell into the abyss.
+    sand_without_floor: Option<usize>,
+    last: Option<Point2>,
+}
+
+impl CaveSimulation {
+    pub fn new(cave: Cave) -> Self {
+        let mut filled = cave.clone();
+        filled.fill_with_sand();
+        filled.fill_completely();
+        Self {
+            bounds: filled.bounds(),
+            sand: cave.sand_count(),
+            cave,
+            sand_without_floor: None,
+            last: None,
+        }
+    }
+}
+
+impl Simulation for CaveSimulation {
+    fn step(&mut self) -> bool {
+        if self.last == Some(SAND_SOURCE) {
+            return false;
+        }
+        self.last = self.cave.drop_sand();
+        match self.last {
+            Some(_) => self.sand += 1,
+            None => {
+                self.cave.filled_with_sand = true;
+                self.sand_without_floor = Some(self.sand);
+            }
+        }
+        true
+    }
+
+    fn render(&self) -> String {
+        self.cave.render_within(self.bounds)
+    }
+
+    fn status(&self) -> Vec<(String, String)> {
+        let floor = if self.cave.filled_with_sand {
+            "placed"
+        } else {
+            "none"
+        };
+        let last = self.last.map_or("-".to_string(), |point| point.to_string());
+        let mut status = vec![
+            ("Floor".to_string(), floor.to_string()),
+            ("Sand".to_string(), self.sand.to_string()),
+            ("Last at rest".to_string(), last),
+        ];
+        if let Some(sand) = self.sand_without_floor {
+            status.push(("Sand without floor".to_string(), sand.to_string()));
+        }
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::CaveSimulation;
+    use advent_util::tui::{Explorer, Simulation};
+
+    const CAVE: &str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn step_through_sand() {
+        let mut explorer = Explorer::new(CaveSimulation::new(CAVE.parse().unwrap()));
+        explorer.forward(25);
+        let status = explorer.current().status();
+        assert!(status.contains(&("Floor".to_string(), "placed".to_string())));
+        assert!(status.contains(&("Sand without floor".to_string(), "24".to_string())));
+
+        explorer.jump_to_end();
+        assert_eq!(explorer.last_step(), Some(94));
+        assert!(explorer
+            .current()
+            .status()
+            .contains(&("Sand".to_string(), "93".to_string())));
+        explorer.back(94);
+        assert!(!explorer.current().render().contains('o'));
+    }
+}
@@ -1,54 +1,144 @@
+use crate::scoring::ScoringRules;
+use advent_util::parse::{pair, ParseError};
 use std::cmp::Ordering;
 use std::str::FromStr;
-use enum_iterator::{next, previous, Sequence};
 
-#[derive(Eq, PartialEq, Ord, Copy, Clone, Sequence)]
-enum Hand {
-    Rock,
-    Paper,
-    Scissors,
+/// Hands of a game and which of them beats which. Every hand beats exactly half of the others,
+/// so the number of hands is odd, like in Rock-Paper-Scissors or Rock-Paper-Scissors-Lizard-Spock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    /// `beats[winner][loser]`
+    beats: Vec<Vec<bool>>,
 }
 
-impl Hand {
-    fn wins(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Hand::Rock, Hand::Scissors)
-            | (Hand::Scissors, Hand::Paper)
-            | (Hand::Paper, Hand::Rock) => true,
-            _ => false,
+impl Rules {
+    /// Every hand beats the half of the others which come right before it in the cycle,
+    /// e.g. Paper beats Rock and Rock beats Scissors.
+    pub fn cyclic(names: &[&str]) -> Result<Self, String> {
+        let count = names.len();
+        let mut beats = Vec::new();
+        for winner in 0..count {
+            for distance in 1..=count / 2 {
+                beats.push((names[winner], names[(winner + count - distance) % count]));
+            }
         }
+        Self::from_table(names, &beats)
     }
 
-    fn points(&self) -> u32 {
-        match self {
-            Hand::Rock => 1,
-            Hand::Paper => 2,
-            Hand::Scissors => 3,
+    /// Rules from the `(winner, loser)` pairs, which must decide every pair of different hands.
+    pub fn from_table(names: &[&str], beats: &[(&str, &str)]) -> Result<Self, String> {
+        let count = names.len();
+        if count < 3 || count.is_multiple_of(2) {
+            return Err(format!("Expected odd number of hands, but was {}", count));
+        }
+        let index = |name: &str| {
+            names
+                .iter()
+                .position(|known| *known == name)
+                .ok_or_else(|| format!("Unknown hand '{}'", name))
+        };
+        let mut table = vec![vec![false; count]; count];
+        for (winner, loser) in beats {
+            let (winner, loser) = (index(winner)?, index(loser)?);
+            if winner == loser || table[loser][winner] {
+                return Err(format!(
+                    "'{}' and '{}' cannot beat each other",
+                    names[winner], names[loser]
+                ));
+            }
+            table[winner][loser] = true;
         }
+        for (hand, row) in table.iter().enumerate() {
+            let wins = row.iter().filter(|beats| **beats).count();
+            if wins != count / 2 {
+                return Err(format!(
+                    "'{}' beats {} hands, but must beat {}",
+                    names[hand],
+                    wins,
+                    count / 2
+                ));
+            }
+        }
+        Ok(Self {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            beats: table,
+        })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::cyclic(&["Rock", "Paper", "Scissors"]).expect("three hands are valid")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"])
+            .expect("five hands are valid")
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn hands(&self) -> impl Iterator<Item = Hand> {
+        (0..self.len()).map(Hand)
     }
 
-    fn opponent_to_win(&self) -> Hand {
-        previous(self).unwrap_or(Hand::Scissors)
+    pub fn hand(&self, name: &str) -> Option<Hand> {
+        self.names.iter().position(|known| known == name).map(Hand)
     }
 
-    fn opponent_to_lose(&self) -> Hand {
-        next(self).unwrap_or(Hand::Rock)
+    pub fn name(&self, hand: Hand) -> &str {
+        &self.names[hand.0]
     }
 }
 
-impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(if self.wins(other) {
+/// Hand of some [Rules], identified by its place in them.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Hand(usize);
+
+impl Hand {
+    pub fn wins(&self, other: &Self, rules: &Rules) -> bool {
+        rules.beats[self.0][other.0]
+    }
+
+    /// Greater when this hand beats the other one, equal when neither beats the other.
+    pub fn compare(&self, other: &Self, rules: &Rules) -> Ordering {
+        if self.wins(other, rules) {
             Ordering::Greater
-        } else if other.wins(self) {
+        } else if other.wins(self, rules) {
             Ordering::Less
         } else {
             Ordering::Equal
-        })
+        }
+    }
+
+    /// Place of the hand in the rules, counted from zero.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    /// The first hand in the order of the rules which this one beats.
+    pub fn opponent_to_win(&self, rules: &Rules) -> Hand {
+        rules
+            .hands()
+            .find(|other| self.wins(other, rules))
+            .expect("every hand beats some other")
+    }
+
+    /// The first hand in the order of the rules which beats this one.
+    pub fn opponent_to_lose(&self, rules: &Rules) -> Hand {
+        rules
+            .hands()
+            .find(|other| other.wins(self, rules))
+            .expect("every hand is beaten by some other")
     }
 }
 
-struct Opponent(Hand);
+pub(crate) struct Opponent(pub(crate) Hand);
 
 impl AsRef<Hand> for Opponent {
     fn as_ref(&self) -> &Hand {
@@ -65,13 +155,12 @@ impl AsRef<Hand> for Player {
 }
 
 trait GameAttender : AsRef<Hand> {
-    fn wins(&self, other_hand: impl AsRef<Hand>) -> bool;
+    fn wins(&self, other_hand: impl AsRef<Hand>, rules: &Rules) -> bool;
 
-    fn play(&self, other_hand: impl AsRef<Hand>) -> GameResult {
-        let other_hand = other_hand.as_ref();
-        if self.as_ref().wins(other_hand) {
+    fn play(&self, other_hand: impl AsRef<Hand>, rules: &Rules) -> GameResult {
+        if self.wins(&other_hand, rules) {
             GameResult::Win
-        } else if other_hand.wins(self.as_ref()) {
+        } else if other_hand.wins(self, rules) {
             GameResult::Loss
         } else {
             GameResult::Draw
@@ -80,46 +169,33 @@ trait GameAttender : AsRef<Hand> {
 }
 
 impl <T> GameAttender for T where T : AsRef<Hand> {
-    fn wins(&self, other_hand: impl AsRef<Hand>) -> bool {
-        self.as_ref().wins(other_hand.as_ref())
+    fn wins(&self, other_hand: impl AsRef<Hand>, rules: &Rules) -> bool {
+        self.as_ref().wins(other_hand.as_ref(), rules)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum GameResult {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameResult {
     Win,
     Draw,
     Loss,
 }
 
-impl GameResult {
-    fn points(&self) -> u32 {
-        match self {
-            GameResult::Win => 6,
-            GameResult::Draw => 3,
-            GameResult::Loss => 0,
-        }
-    }
-}
-
 pub struct Game {
     opponent: Opponent,
     player: Player,
     expected_result: GameResult,
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct ParseError;
-
 impl FromStr for Opponent {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "A" => Ok(Opponent(Hand::Rock)),
-            "B" => Ok(Opponent(Hand::Paper)),
-            "C" => Ok(Opponent(Hand::Scissors)),
-            _ => Err(ParseError),
+            "A" => Ok(Opponent(Hand(0))),
+            "B" => Ok(Opponent(Hand(1))),
+            "C" => Ok(Opponent(Hand(2))),
+            _ => Err(ParseError::new("Expected A, B or C", s)),
         }
     }
 }
@@ -129,10 +205,10 @@ impl FromStr for Player {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "X" => Ok(Player(Hand::Rock)),
-            "Y" => Ok(Player(Hand::Paper)),
-            "Z" => Ok(Player(Hand::Scissors)),
-            _ => Err(ParseError),
+            "X" => Ok(Player(Hand(0))),
+            "Y" => Ok(Player(Hand(1))),
+            "Z" => Ok(Player(Hand(2))),
+            _ => Err(ParseError::new("Expected X, Y or Z", s)),
         }
     }
 }
@@ -145,39 +221,140 @@ impl FromStr for GameResult {
             "X" => Ok(GameResult::Loss),
             "Y" => Ok(GameResult::Draw),
             "Z" => Ok(GameResult::Win),
-            _ => Err(ParseError)
+            _ => Err(ParseError::new("Expected X, Y or Z", s)),
         }
     }
 }
 
+/// Round of the strategy guide, whose hands are the ones of [Rules::rock_paper_scissors].
 impl FromStr for Game {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split_whitespace();
-        let opponent: Opponent = split.next().ok_or(ParseError).and_then(|s| s.parse())?;
-        let second_column = split.next().ok_or(ParseError);
-        let player: Player = second_column.and_then(|s| s.parse())?;
-        let expected_result: GameResult = second_column.and_then(|s| s.parse())?;
+        let (opponent, (player, expected_result)) = pair(s, " ", str::parse, |second_column| {
+            Ok((second_column.parse()?, second_column.parse()?))
+        })?;
         Ok(Game { opponent, player, expected_result })
     }
 }
 
 impl Game {
-    pub fn player_score(&self) -> u32 {
-        let game_result = self.player.play(&self.opponent).points();
-        self.player.as_ref().points() + game_result
+    pub fn new(opponent: Hand, player: Hand, expected_result: GameResult) -> Self {
+        Self {
+            opponent: Opponent(opponent),
+            player: Player(player),
+            expected_result,
+        }
+    }
+
+    /// Game in which the player shows the hand, whatever it results in.
+    pub fn with_hand(opponent: Hand, player: Hand, rules: &Rules) -> Self {
+        let expected_result = Player(player).play(Opponent(opponent), rules);
+        Self::new(opponent, player, expected_result)
     }
 
-    pub fn score_with_guessing(&self) -> u32 {
-        self.expected_result.points() + self.guess_player_hand().points()
+    /// Game in which the player shows the hand giving the result.
+    pub fn with_result(opponent: Hand, expected_result: GameResult, rules: &Rules) -> Self {
+        let player = hand_for_result(&opponent, expected_result, rules);
+        Self::new(opponent, player, expected_result)
     }
 
-    fn guess_player_hand(&self) -> Hand {
-        match self.expected_result {
-            GameResult::Win => self.opponent.as_ref().opponent_to_lose(),
-            GameResult::Draw => *self.opponent.as_ref(),
-            GameResult::Loss => self.opponent.as_ref().opponent_to_win(),
+    pub fn player_score(&self, rules: &Rules, scoring: &ScoringRules) -> u32 {
+        let game_result = self.player.play(&self.opponent, rules);
+        scoring.score(self.player.as_ref(), game_result)
+    }
+
+    pub fn score_with_guessing(&self, rules: &Rules, scoring: &ScoringRules) -> u32 {
+        scoring.score(&self.guess_player_hand(rules), self.expected_result)
+    }
+
+    fn guess_player_hand(&self, rules: &Rules) -> Hand {
+        hand_for_result(self.opponent.as_ref(), self.expected_result, rules)
+    }
+}
+
+fn hand_for_result(opponent: &Hand, result: GameResult, rules: &Rules) -> Hand {
+    match result {
+        GameResult::Win => opponent.opponent_to_lose(rules),
+        GameResult::Draw => *opponent,
+        GameResult::Loss => opponent.opponent_to_win(rules),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{Game, GameResult, Rules};
+    use crate::scoring::ScoringRules;
+    use advent_util::parse::lines;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn rock_paper_scissors() {
+        let rules = Rules::rock_paper_scissors();
+        let hand = |name| rules.hand(name).unwrap();
+        assert!(hand("Rock").wins(&hand("Scissors"), &rules));
+        assert!(hand("Paper").wins(&hand("Rock"), &rules));
+        assert!(hand("Scissors").wins(&hand("Paper"), &rules));
+        assert!(!hand("Rock").wins(&hand("Rock"), &rules));
+        assert!(!hand("Rock").wins(&hand("Paper"), &rules));
+        assert_eq!(hand("Rock").opponent_to_win(&rules), hand("Scissors"));
+        assert_eq!(hand("Rock").opponent_to_lose(&rules), hand("Paper"));
+        assert_eq!(
+            hand("Rock").compare(&hand("Scissors"), &rules),
+            Ordering::Greater
+        );
+        assert_eq!(hand("Rock").compare(&hand("Paper"), &rules), Ordering::Less);
+        assert_eq!(hand("Rock").compare(&hand("Rock"), &rules), Ordering::Equal);
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let scoring = ScoringRules::standard(&rules);
+        let hand = |name| rules.hand(name).unwrap();
+        for (winner, losers) in [
+            ("Rock", ["Scissors", "Lizard"]),
+            ("Paper", ["Rock", "Spock"]),
+            ("Scissors", ["Paper", "Lizard"]),
+            ("Lizard", ["Spock", "Paper"]),
+            ("Spock", ["Scissors", "Rock"]),
+        ] {
+            for loser in losers {
+                assert!(hand(winner).wins(&hand(loser), &rules));
+                assert!(!hand(loser).wins(&hand(winner), &rules));
+            }
         }
+        assert_eq!(rules.name(hand("Lizard").opponent_to_lose(&rules)), "Rock");
+
+        // the same game machinery scores a tournament of the variant
+        let games = [
+            ("Spock", "Lizard", GameResult::Win),
+            ("Scissors", "Scissors", GameResult::Loss),
+        ]
+        .map(|(opponent, player, result)| Game::new(hand(opponent), hand(player), result));
+        let scores = games.iter().map(|game| game.player_score(&rules, &scoring));
+        assert_eq!(scores.collect::<Vec<_>>(), vec![4 + 6, 5 + 3]);
+        let guessed = games
+            .iter()
+            .map(|game| game.score_with_guessing(&rules, &scoring));
+        assert_eq!(guessed.collect::<Vec<_>>(), vec![6 + 3, 3]);
+    }
+
+    #[test]
+    fn parse_error_points_to_second_column() {
+        let error = lines("A Y\nB B", str::parse::<Game>).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: Expected X, Y or Z: 'B'"
+        );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn invalid_tables() {
+        assert!(Rules::cyclic(&["Rock", "Paper"]).is_err());
+        assert!(Rules::from_table(&["A", "B", "C"], &[("A", "B"), ("B", "A")]).is_err());
+        assert!(Rules::from_table(&["A", "B", "C"], &[("A", "B"), ("A", "C")]).is_err());
+        assert!(Rules::from_table(&["A", "B", "C"], &[("A", "D")]).is_err());
+        assert!(Rules::from_table(&["A", "B", "C"], &[("A", "B"), ("B", "C"), ("C", "A")]).is_ok());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::game::{Game, Rules};
+use crate::scoring::ScoringRules;
+use advent_util::parse::lines;
+use advent_util::solution::Solution;
+
+pub mod game;
+pub mod scoring;
+pub mod strategy;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(lines(input, str::parse)?)
+    }
+
+    fn part_one(&self, games: &Self::Input) -> Self::PartOne {
+        let rules = Rules::rock_paper_scissors();
+        let scoring = ScoringRules::standard(&rules);
+        games
+            .iter()
+            .map(|game| game.player_score(&rules, &scoring))
+            .sum()
+    }
+
+    fn part_two(&self, games: &Self::Input) -> Self::PartTwo {
+        let rules = Rules::rock_paper_scissors();
+        let scoring = ScoringRules::standard(&rules);
+        games
+            .iter()
+            .map(|game| game.score_with_guessing(&rules, &scoring))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day2;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day2, env!("CARGO_MANIFEST_DIR"));
+    }
+}
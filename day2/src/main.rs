@@ -1,20 +1,74 @@
-use crate::game::Game;
-use advent_util::read_input;
+use advent_util::output::OutputFormat;
+use advent_util::solution::{
+    exit_with_error, exit_with_usage, run_with_args, take_option, MainArgs, Solution,
+};
+use day2::game::Rules;
+use day2::scoring::ScoringRules;
+use day2::Day2;
+use serde::Serialize;
+use std::env;
+use std::path::{Path, PathBuf};
 
-mod game;
+/// Options of day 2 in front of the ones of [MainArgs].
+const OPTIONS: &str = "[--scoring FILE]... ";
 
 fn main() {
-    let input = read_input(2).unwrap();
-    let games = input
-        .lines()
-        .map(|line| {
-            let game: Game = line.parse().expect(&format!("Cannot parse line: {}", line));
-            game
-        })
-        .collect::<Vec<_>>();
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let scorings = take_scorings(&mut args).unwrap_or_else(|err| exit_with_usage(2, OPTIONS, &err));
+    let args = MainArgs::parse(2, args).unwrap_or_else(|err| exit_with_usage(2, OPTIONS, &err));
+    if scorings.is_empty() {
+        run_with_args(&Day2, &args);
+    } else {
+        print_totals(&args, &scorings);
+    }
+}
+
+/// Removes every `--scoring FILE`, whose rules are used instead of the puzzle ones.
+fn take_scorings(args: &mut Vec<String>) -> Result<Vec<PathBuf>, String> {
+    let mut scorings = Vec::new();
+    while let Some(path) = take_option(args, "--scoring")? {
+        scorings.push(PathBuf::from(path));
+    }
+    Ok(scorings)
+}
+
+/// Totals of both parts under the scoring, printed with `--format json`.
+#[derive(Serialize)]
+struct TotalsRecord<'a> {
+    scoring: &'a Path,
+    part1: u32,
+    part2: u32,
+}
+
+fn print_totals(args: &MainArgs, scorings: &[PathBuf]) {
+    let input = args.input.read().unwrap_or_else(|err| exit_with_error(err));
+    let games = Day2
+        .parse(&input)
+        .unwrap_or_else(|err| exit_with_error(err));
+    let rules = Rules::rock_paper_scissors();
 
-    let player_score: u32 = games.iter().map(|game| game.player_score()).sum();
-    let player_strategy_score: u32 = games.iter().map(|game| game.score_with_guessing()).sum();
-    println!("Player score for strategy: {}", player_score);
-    println!("Player score with strategy: {}", player_strategy_score);
+    for path in scorings {
+        let scoring = ScoringRules::load(path, &rules).unwrap_or_else(|err| exit_with_error(err));
+        let first = games
+            .iter()
+            .map(|game| game.player_score(&rules, &scoring))
+            .sum::<u32>();
+        let second = games
+            .iter()
+            .map(|game| game.score_with_guessing(&rules, &scoring))
+            .sum::<u32>();
+        match args.format {
+            OutputFormat::Text => {
+                println!("{}: part 1 {}, part 2 {}", path.display(), first, second)
+            }
+            OutputFormat::Json => {
+                let record = TotalsRecord {
+                    scoring: path,
+                    part1: first,
+                    part2: second,
+                };
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
 }
@@ -0,0 +1,159 @@
+use crate::game::{GameResult, Hand, Rules};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Points the player gets for the shown hand and for the result of the game, e.g.
+///
+/// ```toml
+/// hands = [1, 2, 3]
+/// win = 6
+/// draw = 3
+/// loss = 0
+/// ```
+///
+/// Every constructor checks the points against the [Rules] they are used with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoringRules {
+    /// Points of the hands in the order of the [Rules].
+    hands: Vec<u32>,
+    win: u32,
+    draw: u32,
+    loss: u32,
+}
+
+impl ScoringRules {
+    /// Scoring of the puzzle: hands are worth their place in the rules counted from one,
+    /// results are worth 6, 3 and 0.
+    pub fn standard(rules: &Rules) -> Self {
+        Self {
+            hands: (1..=rules.len() as u32).collect(),
+            win: 6,
+            draw: 3,
+            loss: 0,
+        }
+    }
+
+    pub fn new(
+        hands: Vec<u32>,
+        win: u32,
+        draw: u32,
+        loss: u32,
+        rules: &Rules,
+    ) -> Result<Self, String> {
+        let scoring = Self {
+            hands,
+            win,
+            draw,
+            loss,
+        };
+        scoring.check(rules)?;
+        Ok(scoring)
+    }
+
+    pub fn from_toml(s: &str, rules: &Rules) -> Result<Self, String> {
+        let scoring = toml::from_str::<Self>(s).map_err(|err| err.to_string())?;
+        scoring.check(rules)?;
+        Ok(scoring)
+    }
+
+    pub fn load(path: &Path, rules: &Rules) -> Result<Self, String> {
+        fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| Self::from_toml(&content, rules))
+            .map_err(|err| format!("Cannot read scoring from '{}': {}", path.display(), err))
+    }
+
+    /// Checks that every hand of the rules has its points.
+    fn check(&self, rules: &Rules) -> Result<(), String> {
+        if self.hands.len() != rules.len() {
+            return Err(format!(
+                "Expected points of {} hands, but was {}",
+                rules.len(),
+                self.hands.len()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Panics if the hand is not of the rules the scoring was checked against.
+    pub fn hand_points(&self, hand: &Hand) -> u32 {
+        *self.hands.get(hand.index()).unwrap_or_else(|| {
+            panic!(
+                "Hand {} is not one of the {} scored hands",
+                hand.index(),
+                self.hands.len()
+            )
+        })
+    }
+
+    pub fn result_points(&self, result: GameResult) -> u32 {
+        match result {
+            GameResult::Win => self.win,
+            GameResult::Draw => self.draw,
+            GameResult::Loss => self.loss,
+        }
+    }
+
+    pub fn score(&self, hand: &Hand, result: GameResult) -> u32 {
+        self.hand_points(hand) + self.result_points(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{Game, Rules};
+    use crate::scoring::ScoringRules;
+    use advent_util::parse::lines;
+
+    const GUIDE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn totals_under_different_scoring() {
+        let rules = Rules::rock_paper_scissors();
+        let games = lines(GUIDE, str::parse::<Game>).unwrap();
+        let winner_takes_all =
+            ScoringRules::from_toml("hands = [0, 0, 0]\nwin = 1\ndraw = 0\nloss = 0\n", &rules)
+                .unwrap();
+        assert_eq!(
+            ScoringRules::new(vec![0, 0, 0], 1, 0, 0, &rules),
+            Ok(winner_takes_all.clone())
+        );
+
+        let totals = [ScoringRules::standard(&rules), winner_takes_all].map(|scoring| {
+            let first = games.iter().map(|game| game.player_score(&rules, &scoring));
+            let second = games
+                .iter()
+                .map(|game| game.score_with_guessing(&rules, &scoring));
+            (first.sum::<u32>(), second.sum::<u32>())
+        });
+        assert_eq!(totals, [(15, 12), (1, 1)]);
+    }
+
+    #[test]
+    fn wrong_scoring() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        assert!(ScoringRules::new(vec![1, 2, 3, 4, 5], 6, 3, 0, &rules).is_ok());
+        assert_eq!(
+            ScoringRules::new(vec![1, 2, 3], 6, 3, 0, &rules),
+            Err("Expected points of 5 hands, but was 3".to_string())
+        );
+        assert!(ScoringRules::from_toml(
+            "hands = [1, 2, 3]\nwin = 6\ndraw = 3\nloss = 0\n",
+            &rules
+        )
+        .is_err());
+        assert!(ScoringRules::from_toml("hands = [1, 2, 3]\nwin = 6\n", &rules).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "not one of the 3 scored hands")]
+    fn hand_of_other_rules() {
+        let scoring = ScoringRules::standard(&Rules::rock_paper_scissors());
+        // the last of the five hands
+        let scissors = Rules::rock_paper_scissors_lizard_spock()
+            .hand("Scissors")
+            .unwrap();
+        scoring.hand_points(&scissors);
+    }
+}
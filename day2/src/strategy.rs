@@ -0,0 +1,309 @@
+//! Evaluation of the strategy guide under every reading of its second column.
+
+use crate::game::{Game, GameResult, Hand, Opponent, Rules};
+use crate::scoring::ScoringRules;
+use advent_util::parse::{lines, pair, ParseError};
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
+use rand::Rng;
+use std::cmp::Reverse;
+use std::str::FromStr;
+
+/// Letters of the second column, `X`, `Y` and `Z`.
+pub const COLUMNS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Hand,
+    /// Index of the letter in the second column, counted from `X`.
+    pub column: usize,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (Opponent(opponent), column) = pair(s, " ", str::parse, |column| match column {
+            "X" => Ok(0),
+            "Y" => Ok(1),
+            "Z" => Ok(2),
+            _ => Err(ParseError::new("Expected X, Y or Z", column)),
+        })?;
+        Ok(Round { opponent, column })
+    }
+}
+
+/// What the letters of the second column stand for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    /// Hands the player shows.
+    Hands([Hand; COLUMNS]),
+    /// Results the player has to get.
+    Results([GameResult; COLUMNS]),
+}
+
+impl Interpretation {
+    /// Every way to give the letters different hands of the rules or different results.
+    pub fn all(rules: &Rules) -> Vec<Self> {
+        let hands = rules.hands().collect::<Vec<_>>();
+        let results = [GameResult::Loss, GameResult::Draw, GameResult::Win];
+        let mut interpretations = permutations(&hands)
+            .into_iter()
+            .map(Interpretation::Hands)
+            .collect::<Vec<_>>();
+        interpretations.extend(
+            permutations(&results)
+                .into_iter()
+                .map(Interpretation::Results),
+        );
+        interpretations
+    }
+
+    pub fn game(&self, round: &Round, rules: &Rules) -> Game {
+        match self {
+            Interpretation::Hands(hands) => {
+                Game::with_hand(round.opponent, hands[round.column], rules)
+            }
+            Interpretation::Results(results) => {
+                Game::with_result(round.opponent, results[round.column], rules)
+            }
+        }
+    }
+
+    pub fn describe(&self, rules: &Rules) -> String {
+        let meanings = match self {
+            Interpretation::Hands(hands) => hands.map(|hand| rules.name(hand).to_string()),
+            Interpretation::Results(results) => results.map(|result| format!("{:?}", result)),
+        };
+        ["X", "Y", "Z"]
+            .iter()
+            .zip(meanings)
+            .map(|(letter, meaning)| format!("{}={}", letter, meaning))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Ordered choices of [COLUMNS] different items.
+fn permutations<T: Copy>(items: &[T]) -> Vec<[T; COLUMNS]> {
+    let mut permutations = Vec::new();
+    for (x, &first) in items.iter().enumerate() {
+        for (y, &second) in items.iter().enumerate() {
+            for (z, &third) in items.iter().enumerate() {
+                if x != y && y != z && x != z {
+                    permutations.push([first, second, third]);
+                }
+            }
+        }
+    }
+    permutations
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Evaluation {
+    pub interpretation: Interpretation,
+    pub total: u32,
+}
+
+pub struct StrategyGuide {
+    rounds: Vec<Round>,
+}
+
+impl StrategyGuide {
+    pub fn new(rounds: Vec<Round>) -> Self {
+        Self { rounds }
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    pub fn total(
+        &self,
+        interpretation: &Interpretation,
+        rules: &Rules,
+        scoring: &ScoringRules,
+    ) -> u32 {
+        self.rounds
+            .iter()
+            .map(|round| {
+                interpretation
+                    .game(round, rules)
+                    .player_score(rules, scoring)
+            })
+            .sum()
+    }
+
+    /// Totals of every interpretation from the best, ties are ordered as in [Interpretation::all].
+    pub fn evaluate(&self, rules: &Rules, scoring: &ScoringRules) -> Vec<Evaluation> {
+        let mut evaluations = Interpretation::all(rules)
+            .into_iter()
+            .map(|interpretation| Evaluation {
+                interpretation,
+                total: self.total(&interpretation, rules, scoring),
+            })
+            .collect::<Vec<_>>();
+        evaluations.sort_by_key(|evaluation| Reverse(evaluation.total));
+        evaluations
+    }
+
+    pub fn best(&self, rules: &Rules, scoring: &ScoringRules) -> Evaluation {
+        self.evaluate(rules, scoring)[0]
+    }
+
+    pub fn worst(&self, rules: &Rules, scoring: &ScoringRules) -> Evaluation {
+        *self
+            .evaluate(rules, scoring)
+            .last()
+            .expect("there are interpretations of three hands")
+    }
+}
+
+impl FromStr for StrategyGuide {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(lines(s, str::parse)?))
+    }
+}
+
+/// Opponent who ignores the guide and shows hands with the given weights.
+pub struct OpponentSimulation {
+    weights: WeightedIndex<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulationSummary {
+    pub trials: usize,
+    pub mean: f64,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl OpponentSimulation {
+    /// Weights of the hands in the order of the rules.
+    pub fn new(weights: &[f64], rules: &Rules) -> Result<Self, String> {
+        if weights.len() != rules.len() {
+            return Err(format!(
+                "Expected {} weights, but was {}",
+                rules.len(),
+                weights.len()
+            ));
+        }
+        let weights =
+            WeightedIndex::new(weights).map_err(|err| format!("Wrong weights: {}", err))?;
+        Ok(Self { weights })
+    }
+
+    /// Plays the rounds of the guide `trials` times, each time with new hands of the opponent,
+    /// and summarises totals of the player following the interpretation.
+    pub fn simulate(
+        &self,
+        guide: &StrategyGuide,
+        interpretation: &Interpretation,
+        rules: &Rules,
+        scoring: &ScoringRules,
+        trials: usize,
+        rng: &mut impl Rng,
+    ) -> Option<SimulationSummary> {
+        let hands = rules.hands().collect::<Vec<_>>();
+        let totals = (0..trials)
+            .map(|_| {
+                guide
+                    .rounds
+                    .iter()
+                    .map(|round| {
+                        let round = Round {
+                            opponent: hands[self.weights.sample(rng)],
+                            column: round.column,
+                        };
+                        interpretation
+                            .game(&round, rules)
+                            .player_score(rules, scoring)
+                    })
+                    .sum::<u32>()
+            })
+            .collect::<Vec<_>>();
+        Some(SimulationSummary {
+            trials,
+            mean: totals.iter().map(|&total| total as f64).sum::<f64>() / trials as f64,
+            min: *totals.iter().min()?,
+            max: *totals.iter().max()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{GameResult, Rules};
+    use crate::scoring::ScoringRules;
+    use crate::strategy::{Interpretation, OpponentSimulation, StrategyGuide};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const GUIDE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn search_interpretations() {
+        let rules = Rules::rock_paper_scissors();
+        let scoring = ScoringRules::standard(&rules);
+        let guide = GUIDE.parse::<StrategyGuide>().unwrap();
+        let hand = |name| rules.hand(name).unwrap();
+
+        let puzzle_hands = Interpretation::Hands([hand("Rock"), hand("Paper"), hand("Scissors")]);
+        let puzzle_results =
+            Interpretation::Results([GameResult::Loss, GameResult::Draw, GameResult::Win]);
+        assert_eq!(guide.total(&puzzle_hands, &rules, &scoring), 15);
+        assert_eq!(guide.total(&puzzle_results, &rules, &scoring), 12);
+
+        let evaluations = guide.evaluate(&rules, &scoring);
+        assert_eq!(evaluations.len(), 12);
+        let best = guide.best(&rules, &scoring);
+        let worst = guide.worst(&rules, &scoring);
+        assert!(evaluations
+            .iter()
+            .all(|evaluation| worst.total <= evaluation.total && evaluation.total <= best.total));
+        // winning every round with different hands, and losing every one
+        assert_eq!(best.total, 24);
+        assert_eq!(
+            best.interpretation,
+            Interpretation::Hands([hand("Scissors"), hand("Paper"), hand("Rock")])
+        );
+        assert_eq!(worst.total, 6);
+        assert_eq!(
+            guide.total(&best.interpretation, &rules, &scoring),
+            best.total
+        );
+        assert_eq!(puzzle_results.describe(&rules), "X=Loss, Y=Draw, Z=Win");
+    }
+
+    #[test]
+    fn simulate_opponent() {
+        let rules = Rules::rock_paper_scissors();
+        let scoring = ScoringRules::standard(&rules);
+        let guide = GUIDE.parse::<StrategyGuide>().unwrap();
+        let hand = |name| rules.hand(name).unwrap();
+        let interpretation = Interpretation::Hands([hand("Rock"), hand("Paper"), hand("Scissors")]);
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let always_rock = OpponentSimulation::new(&[1.0, 0.0, 0.0], &rules).unwrap();
+        let summary = always_rock
+            .simulate(&guide, &interpretation, &rules, &scoring, 10, &mut rng)
+            .unwrap();
+        assert_eq!((summary.min, summary.max, summary.mean), (15, 15, 15.0));
+
+        // every hand scores its points and 3 on average against a uniform opponent
+        let uniform = OpponentSimulation::new(&[1.0, 1.0, 1.0], &rules).unwrap();
+        let summary = uniform
+            .simulate(&guide, &interpretation, &rules, &scoring, 10000, &mut rng)
+            .unwrap();
+        assert!((summary.mean - 15.0).abs() < 0.3, "{:?}", summary);
+        assert!(summary.min < summary.max);
+
+        assert!(uniform
+            .simulate(&guide, &interpretation, &rules, &scoring, 0, &mut rng)
+            .is_none());
+        assert!(OpponentSimulation::new(&[1.0, 1.0], &rules).is_err());
+        assert!(OpponentSimulation::new(&[0.0, 0.0, 0.0], &rules).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use crate::rucksack::Rucksack;
+use advent_util::solution::Solution;
+use advent_util::windows::chunks;
+
+mod rucksack;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(input.lines().map(Rucksack::new).collect())
+    }
+
+    fn part_one(&self, rucksacks: &Self::Input) -> Self::PartOne {
+        rucksacks.iter().map(|r| r.wrong_item_priority()).sum()
+    }
+
+    fn part_two(&self, rucksacks: &Self::Input) -> Self::PartTwo {
+        chunks::<_, 3>(rucksacks)
+            .map(|[a, b, c]| a.badge_priority(b, c))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day3;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day3, env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -6,9 +6,9 @@ struct Item(char);
 
 impl Item {
     fn as_index(&self) -> usize {
-        if ('a'..='z').contains(&self.0) {
+        if self.0.is_ascii_lowercase() {
             self.0 as usize - 'a' as usize
-        } else if ('A'..='Z').contains(&self.0) {
+        } else if self.0.is_ascii_uppercase() {
             self.0 as usize - 'A' as usize + HALF
         } else {
             panic!("Unexpected char '{}'", self.0);
@@ -16,9 +16,9 @@ impl Item {
     }
 
     fn from_index(index: usize) -> Option<Self> {
-        if (0..=25 as usize).contains(&index) {
+        if (0..=25usize).contains(&index) {
             Some(Self(char::from_u32('a' as u32 + index as u32).unwrap()))
-        } else if (26..=51 as usize).contains(&index) {
+        } else if (26..=51usize).contains(&index) {
             Some(Self(
                 char::from_u32('A' as u32 + index as u32 - HALF as u32).unwrap(),
             ))
@@ -54,7 +54,7 @@ impl<'a> Iterator for ItemsTypeIterator<'a> {
         current_item += 1;
         self.current_item = Some(current_item);
 
-        return item;
+        item
     }
 }
 
@@ -64,15 +64,15 @@ struct Compartment {
 
 impl Compartment {
     fn new(s: &str) -> Self {
-        let mut items = [0 as u32; SIZE];
-        for item in s.chars().map(|c| Item(c)) {
+        let mut items = [0u32; SIZE];
+        for item in s.chars().map(Item) {
             items[item.as_index()] += 1;
         }
 
         Self { items }
     }
 
-    fn iter_types(&self) -> ItemsTypeIterator {
+    fn iter_types(&self) -> ItemsTypeIterator<'_> {
         ItemsTypeIterator {
             items: &self.items,
             current_item: None,
@@ -84,7 +84,7 @@ impl Compartment {
     }
 
     fn intersect(&self, other: &Compartment) -> Compartment {
-        let mut items = [0 as u32; SIZE];
+        let mut items = [0u32; SIZE];
 
         for item in self.iter_types() {
             if other.contains(&item) {
@@ -131,13 +131,14 @@ impl Rucksack {
             .iter_types()
             .next()
             .expect("Group doesn't contain badge");
-        return badge.priority();
+        badge.priority()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn iter_test() {
@@ -169,4 +170,27 @@ mod tests {
         assert!(compartment.contains(&Item('Z')));
         assert!(compartment.contains(&Item('z')));
     }
+
+    /// Item types of both strings in the order of their priorities.
+    fn brute_force_intersection(first: &str, second: &str) -> Vec<Item> {
+        let mut common = first
+            .chars()
+            .filter(|item| second.contains(*item))
+            .map(Item)
+            .collect::<Vec<_>>();
+        common.sort_by_key(Item::priority);
+        common.dedup();
+        common
+    }
+
+    proptest! {
+        #[test]
+        fn intersect_matches_brute_force(first in "[a-zA-Z]{0,30}", second in "[a-zA-Z]{0,30}") {
+            let intersection = Compartment::new(&first).intersect(&Compartment::new(&second));
+            prop_assert_eq!(
+                intersection.iter_types().collect::<Vec<_>>(),
+                brute_force_intersection(&first, &second)
+            );
+        }
+    }
 }
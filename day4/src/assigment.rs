@@ -1,3 +1,4 @@
+use advent_util::parse::{number, pair, ParseError};
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
@@ -9,26 +10,20 @@ pub struct Assignments {
 struct MyRange(RangeInclusive<u32>);
 
 impl FromStr for MyRange {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split("-");
-        let start: u32 = split.next().ok_or(())?.parse().expect("Cannot parse start");
-        let end: u32 = split.next().ok_or(())?.parse().expect("Cannot parse end");
+        let (start, end) = pair(s, "-", number, number)?;
         Ok(Self(start..=end))
     }
 }
 
 impl FromStr for Assignments {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(",");
-        let first_assigment = split.next().ok_or(())?;
-        let second_assigment = split.next().ok_or(())?;
-
-        let first_assigment = first_assigment.parse::<MyRange>()?.0;
-        let second_assigment = second_assigment.parse::<MyRange>()?.0;
+        let (MyRange(first_assigment), MyRange(second_assigment)) =
+            pair(s, ",", str::parse, str::parse)?;
 
         Ok(Self {
             first_assigment,
@@ -0,0 +1,46 @@
+use crate::assigment::Assignments;
+use advent_util::parse::lines;
+use advent_util::solution::Solution;
+
+mod assigment;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Assignments>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(lines(input, str::parse)?)
+    }
+
+    fn part_one(&self, assignments: &Self::Input) -> Self::PartOne {
+        assignments
+            .iter()
+            .filter(|assigment| assigment.does_one_contain_another())
+            .count()
+    }
+
+    fn part_two(&self, assignments: &Self::Input) -> Self::PartTwo {
+        assignments
+            .iter()
+            .filter(|assigment| assigment.intersects())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day4;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day4, env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,21 @@
+use advent_util::bench::bench_input;
+use advent_util::solution::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day5::Day5;
+use std::hint::black_box;
+
+fn bench(c: &mut Criterion) {
+    let input = bench_input(5, env!("CARGO_MANIFEST_DIR"));
+    let parsed = Day5.parse(&input).unwrap();
+
+    c.bench_function("day5 parse", |b| b.iter(|| Day5.parse(black_box(&input))));
+    c.bench_function("day5 part 1", |b| {
+        b.iter(|| Day5.part_one(black_box(&parsed)))
+    });
+    c.bench_function("day5 part 2", |b| {
+        b.iter(|| Day5.part_two(black_box(&parsed)))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -1,3 +1,4 @@
+use advent_util::parse::{number, pair, parse_after, ParseError};
 use std::str::FromStr;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -11,37 +12,51 @@ pub struct Crates {
 }
 
 impl Crates {
-    pub fn from_lines(lines: &[&str]) -> Self {
-        let titles = lines.last().expect("No title line!");
-        let last_title: usize = titles
+    /// Levels of crates from the top and the line of stack numbers below them.
+    pub fn from_lines(lines: &[&str]) -> Result<Self, ParseError> {
+        let (titles, levels) = lines
+            .split_last()
+            .ok_or_else(|| ParseError::new("Expected crates", "").at(1, 1))?;
+        let last_title = titles
             .split_whitespace()
             .last()
-            .expect("At least one title should be")
-            .parse()
-            .unwrap();
+            .ok_or_else(|| ParseError::new("Expected stack numbers", titles))
+            .and_then(number::<usize>)
+            .map_err(|err| err.at(lines.len(), 1))?;
 
         let mut stacks = vec![CratesStack::new(); last_title];
 
-        for crates_level in lines.iter().rev().skip(1) {
-            let crates_level: CratesLevel = crates_level.parse().unwrap();
-            if crates_level.0.len() > stacks.len() {
-                panic!("Wrong level!")
+        for (i, crates_level) in levels.iter().enumerate().rev() {
+            let level = crates_level
+                .parse::<CratesLevel>()
+                .map_err(|err| err.offset(i + 1, 1))?;
+            if level.0.len() > stacks.len() {
+                return Err(ParseError::new(
+                    format!("Expected at most {} stacks", stacks.len()),
+                    crates_level,
+                )
+                .at(i + 1, 1));
             }
-            for (i, current_crate) in crates_level.0.iter().enumerate() {
+            for (i, current_crate) in level.0.into_iter().enumerate() {
                 if let Some(current_crate) = current_crate {
-                    stacks[i].push(current_crate.clone());
+                    stacks[i].push(current_crate);
                 }
             }
         }
 
-        Self { stacks }
+        Ok(Self { stacks })
+    }
+
+    /// Numbers of crates in the stacks, which change the same way with both cranes.
+    pub fn heights(&self) -> Vec<usize> {
+        self.stacks.iter().map(|stack| stack.len()).collect()
     }
 
     pub fn move_crates(&mut self, move_info: &Move) {
         for _ in 0..move_info.crate_count {
             let crate_to_move = self.stacks[move_info.stack_from]
                 .pop()
-                .expect(&format!("Cannot execute move: {:?}", move_info));
+                .unwrap_or_else(|| panic!("Cannot execute move: {:?}", move_info));
             self.stacks[move_info.stack_to].push(crate_to_move);
         }
     }
@@ -68,29 +83,40 @@ struct CratesLevel(Vec<Option<Crate>>);
 struct OptionCrate(Option<Crate>);
 
 impl FromStr for OptionCrate {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.strip_prefix('[')
-                .and_then(|s| s.strip_suffix("] ").or_else(|| s.strip_suffix(']')))
-                .and_then(|s| s.chars().next())
-                .map(|c| Crate(c)),
-        ))
+        if s.trim().is_empty() {
+            return Ok(Self(None));
+        }
+        s.trim_end()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .filter(|name| name.chars().count() == 1)
+            .and_then(|name| name.chars().next())
+            .map(|name| Self(Some(Crate(name))))
+            .ok_or_else(|| ParseError::new("Expected crate like '[A]' or spaces", s))
     }
 }
 
 impl FromStr for CratesLevel {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.as_bytes()
-                .chunks(4)
-                .map(|bytes| std::str::from_utf8(bytes).unwrap())
-                .map(|s| s.parse::<OptionCrate>().unwrap().0)
-                .collect(),
-        ))
+        let chars = s.chars().collect::<Vec<_>>();
+        chars
+            .chunks(4)
+            .enumerate()
+            .map(|(i, chunk)| {
+                chunk
+                    .iter()
+                    .collect::<String>()
+                    .parse::<OptionCrate>()
+                    .map(|option_crate| option_crate.0)
+                    .map_err(|err| err.at(1, 4 * i + 1))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
     }
 }
 
@@ -101,16 +127,37 @@ pub struct Move {
     stack_to: usize,
 }
 
+impl Move {
+    /// Moves the numbers of crates like the crane moves the crates, errors if there are not
+    /// enough crates or no such stacks.
+    pub fn move_heights(&self, heights: &mut [usize]) -> Result<(), String> {
+        let stacks = heights.len();
+        if self.stack_from >= stacks || self.stack_to >= stacks {
+            return Err(format!("Expected stacks from 1 to {}", stacks));
+        }
+        if heights[self.stack_from] < self.crate_count {
+            return Err(format!(
+                "Cannot move {} crates from stack {} of {}",
+                self.crate_count,
+                self.stack_from + 1,
+                heights[self.stack_from]
+            ));
+        }
+        heights[self.stack_from] -= self.crate_count;
+        heights[self.stack_to] += self.crate_count;
+        Ok(())
+    }
+}
+
 impl FromStr for Move {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(" ");
-        let crate_count: usize = split.nth(1).unwrap().parse().unwrap();
-        let stack_from: usize = split.nth(1).unwrap().parse().unwrap();
-        let stack_from = stack_from - 1;
-        let stack_to: usize = split.nth(1).unwrap().parse().unwrap();
-        let stack_to = stack_to - 1;
+        let (crate_count, (stack_from, stack_to)) = parse_after(s, "move ", |rest| {
+            pair(rest, " from ", number, |rest| {
+                pair(rest, " to ", stack, stack)
+            })
+        })?;
 
         Ok(Self {
             crate_count,
@@ -120,9 +167,16 @@ impl FromStr for Move {
     }
 }
 
+/// Index of the stack numbered from 1.
+fn stack(text: &str) -> Result<usize, ParseError> {
+    number::<usize>(text)?
+        .checked_sub(1)
+        .ok_or_else(|| ParseError::new("Stacks are numbered from 1", text))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::crates::{Crate, CratesLevel, Move, OptionCrate};
+    use crate::crates::{Crate, Crates, CratesLevel, Move, OptionCrate};
 
     #[test]
     fn crates_level_parse() {
@@ -158,5 +212,34 @@ mod tests {
                 stack_to: 2
             })
         );
+
+        let error = "move 1 from 0 to 3".parse::<Move>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 13: Stacks are numbered from 1: '0'"
+        );
+        assert!("move 1 from 2".parse::<Move>().is_err());
+    }
+
+    #[test]
+    fn wrong_crates() {
+        let error = "[A] (B)".parse::<CratesLevel>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: Expected crate like '[A]' or spaces: '(B)'"
+        );
+        assert!(Crates::from_lines(&["[A] [B]", " 1 "]).is_err());
+        assert!(Crates::from_lines(&[]).is_err());
+
+        let mut heights = Crates::from_lines(&["[A]    ", " 1   2 "])
+            .unwrap()
+            .heights();
+        let two_crates = "move 2 from 1 to 2".parse::<Move>().unwrap();
+        assert!(two_crates.move_heights(&mut heights).is_err());
+        assert!("move 1 from 3 to 1"
+            .parse::<Move>()
+            .unwrap()
+            .move_heights(&mut heights)
+            .is_err());
     }
 }
@@ -0,0 +1,115 @@
+use crate::crates::{Crates, Move};
+use advent_util::parse::ParseError;
+use advent_util::solution::Solution;
+
+mod crates;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Crates, Vec<Move>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let blank = lines
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .ok_or_else(|| {
+                ParseError::new(
+                    "Expected blank line between crates and moves",
+                    lines.last().unwrap_or(&""),
+                )
+                .at(lines.len().max(1), 1)
+            })?;
+
+        let crates = Crates::from_lines(&lines[..blank])?;
+
+        let mut heights = crates.heights();
+        let mut moves = Vec::new();
+        for (i, line) in lines.iter().enumerate().skip(blank + 1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let current_move = line
+                .parse::<Move>()
+                .and_then(|current_move| {
+                    current_move
+                        .move_heights(&mut heights)
+                        .map_err(|message| ParseError::new(message, line))?;
+                    Ok(current_move)
+                })
+                .map_err(|err| err.offset(i + 1, 1))?;
+            moves.push(current_move);
+        }
+        Ok((crates, moves))
+    }
+
+    fn part_one(&self, (crates, moves): &Self::Input) -> Self::PartOne {
+        let mut crates = crates.clone();
+        for current_move in moves.iter() {
+            crates.move_crates(current_move);
+        }
+        peeks_as_string(&crates)
+    }
+
+    fn part_two(&self, (crates, moves): &Self::Input) -> Self::PartTwo {
+        let mut crates = crates.clone();
+        for current_move in moves.iter() {
+            crates.move_crates_new(current_move);
+        }
+        peeks_as_string(&crates)
+    }
+}
+
+fn peeks_as_string(crates: &Crates) -> String {
+    crates
+        .peeks()
+        .iter()
+        .map(|peek| {
+            peek.map(|peek| format!("{}", peek))
+                .unwrap_or("".to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day5;
+    use advent_util::examples::assert_fixtures;
+    use advent_util::solution::Solution;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day5, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn wrong_input() {
+        let crates = "    [D]\n[N] [C]\n 1   2 \n";
+        assert_eq!(
+            Day5.parse(crates).err(),
+            Some(
+                "line 3, column 1: Expected blank line between crates and moves: ' 1   2 '"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            Day5.parse(&format!(
+                "{}\nmove 1 from 1 to 2\nmove 3 from 1 to 2",
+                crates
+            ))
+            .err(),
+            Some(
+                "line 6, column 1: Cannot move 3 crates from stack 1 of 0: 'move 3 from 1 to 2'"
+                    .to_string()
+            )
+        );
+        assert!(Day5.parse("").is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use advent_util::parse::ParseError;
+use advent_util::solution::Solution;
+use advent_util::windows::WindowsExt;
+
+const SIZE: usize = 26;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    /// Checks that there are only lowercase letters and a start-of-message marker, which
+    /// comes after a start-of-packet one.
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        let datastream = input.trim();
+        if let Some((i, signal)) = datastream
+            .char_indices()
+            .find(|(_, signal)| !signal.is_ascii_lowercase())
+        {
+            let column = datastream[..i].chars().count() + 1;
+            return Err(
+                ParseError::new("Expected lowercase letter", &signal.to_string())
+                    .at(1, column)
+                    .into(),
+            );
+        }
+        if search_for_start_of::<14>(datastream).is_none() {
+            return Err(
+                ParseError::new("Expected 14 different letters in a row", datastream).into(),
+            );
+        }
+        Ok(datastream.to_string())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::PartOne {
+        search_for_start_of::<4>(input).expect("checked while parsing") + 4
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::PartTwo {
+        search_for_start_of::<14>(input).expect("checked while parsing") + 14
+    }
+}
+
+fn search_for_start_of<const N: usize>(input: &str) -> Option<usize> {
+    input
+        .bytes()
+        .sliding::<N>()
+        .position(|signals| is_begin_of_message(&signals))
+}
+
+fn is_begin_of_message<const N: usize>(signals: &[u8; N]) -> bool {
+    let mut characters = [false; SIZE];
+    for signal in signals {
+        characters[(*signal - b'a') as usize] = true;
+    }
+    characters
+        .iter()
+        .map(|has_char| if *has_char { 1 } else { 0 })
+        .sum::<usize>()
+        == N
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{search_for_start_of, Day6};
+    use advent_util::examples::assert_fixtures;
+    use advent_util::solution::Solution;
+    use proptest::prelude::*;
+    use proptest::sample::subsequence;
+    use std::collections::HashSet;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day6, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn wrong_input() {
+        assert_eq!(
+            Day6.parse("abcD").err(),
+            Some("line 1, column 4: Expected lowercase letter: 'D'".to_string())
+        );
+        assert!(Day6.parse("abcdefg").is_err());
+        assert!(Day6.parse("").is_err());
+    }
+
+    /// Position of the first `size` different characters in a row.
+    fn brute_force_start_of(input: &str, size: usize) -> Option<usize> {
+        let chars = input.chars().collect::<Vec<_>>();
+        (0..chars.len().saturating_sub(size - 1)).find(|&start| {
+            chars[start..start + size]
+                .iter()
+                .collect::<HashSet<_>>()
+                .len()
+                == size
+        })
+    }
+
+    /// Random letters around 14 different ones, so markers of both parts are found.
+    fn datastream() -> impl Strategy<Value = String> {
+        let letters = ('a'..='z').collect::<Vec<_>>();
+        (
+            "[a-z]{0,40}",
+            subsequence(letters, 14).prop_shuffle(),
+            "[a-z]{0,40}",
+        )
+            .prop_map(|(before, marker, after)| {
+                before + &marker.into_iter().collect::<String>() + &after
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn start_of_matches_brute_force(input in datastream()) {
+            prop_assert_eq!(search_for_start_of::<4>(&input), brute_force_start_of(&input, 4));
+            prop_assert_eq!(search_for_start_of::<14>(&input), brute_force_start_of(&input, 14));
+        }
+    }
+}
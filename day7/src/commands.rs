@@ -1,3 +1,4 @@
+use advent_util::parse::{number, ParseError};
 use std::str::FromStr;
 
 #[derive(Debug, Clone)]
@@ -25,11 +26,8 @@ pub enum Input {
     LsOutput(LsOutput),
 }
 
-#[derive(Debug, Clone)]
-pub struct CommandParseError(String);
-
 impl FromStr for Command {
-    type Err = CommandParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let command = s
@@ -44,35 +42,32 @@ impl FromStr for Command {
             })
             .or_else(|| Some(s).filter(|s| s.trim() == "ls").map(|_| Command::Ls));
 
-        command.ok_or_else(|| CommandParseError(format!("Unknown command: {}", s)))
+        command.ok_or_else(|| ParseError::new("Unknown command", s))
     }
 }
 
 impl FromStr for LsOutput {
-    type Err = CommandParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some(dir_name) = s.strip_prefix("dir ") {
             Ok(LsOutput::Dir(dir_name.to_string()))
         } else if let Some((size, name)) = s.split_once(" ") {
-            let size: u32 = size.parse().map_err(|err| {
-                CommandParseError(format!("Cannot parse output '{}' because {}", s, err))
-            })?;
-            Ok(LsOutput::File(name.to_string(), size))
+            Ok(LsOutput::File(name.to_string(), number(size)?))
         } else {
-            Err(CommandParseError(format!("Unknown output format: {}", s)))
+            Err(ParseError::new("Unknown output format", s))
         }
     }
 }
 
 impl FromStr for Input {
-    type Err = CommandParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some(cmd) = s.strip_prefix("$ ") {
-            cmd.parse::<Command>().map(|cmd| Input::Command(cmd))
+            cmd.parse::<Command>().map(Input::Command)
         } else {
-            s.parse::<LsOutput>().map(|output| Input::LsOutput(output))
+            s.parse::<LsOutput>().map(Input::LsOutput)
         }
     }
 }
@@ -9,6 +9,7 @@ pub struct DirectoryDescriptor {
 
 #[derive(Debug)]
 pub enum FileItem {
+    #[allow(dead_code)] // file name is only shown in debug output
     File(String, u32),
     Directory(DirectoryDescriptor),
 }
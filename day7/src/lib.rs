@@ -0,0 +1,100 @@
+use crate::commands::{CdArg, Command, Input, LsOutput};
+use crate::files::{FileItem, FileTree};
+use advent_util::parse::lines;
+use advent_util::solution::Solution;
+
+mod commands;
+mod files;
+
+const MAX_SIZE: u32 = 100000;
+const TOTAL_SIZE: u32 = 70000000;
+const NECESSARY_FREE_SPACE: u32 = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileTree;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        let input: Vec<Input> = lines(input, str::parse)?;
+        Ok(build_file_tree(&input))
+    }
+
+    fn part_one(&self, file_tree: &Self::Input) -> Self::PartOne {
+        file_sizes(file_tree)
+            .iter()
+            .filter(|(file, size)| {
+                if let FileItem::Directory(_) = file {
+                    *size <= MAX_SIZE
+                } else {
+                    false
+                }
+            })
+            .map(|(_, size)| size)
+            .sum()
+    }
+
+    fn part_two(&self, file_tree: &Self::Input) -> Self::PartTwo {
+        let file_sizes = file_sizes(file_tree);
+        let (_, root_size) = file_sizes[0];
+
+        file_sizes
+            .iter()
+            .filter(|(file, size)| {
+                if let FileItem::Directory(_) = file {
+                    TOTAL_SIZE - root_size + size >= NECESSARY_FREE_SPACE
+                } else {
+                    false
+                }
+            })
+            .map(|(_, size)| *size)
+            .min()
+            .unwrap()
+    }
+}
+
+fn file_sizes(file_tree: &FileTree) -> Vec<(&FileItem, u32)> {
+    file_tree.traverse(|file, children_results| match file {
+        FileItem::File(_, size) => *size,
+        FileItem::Directory(_) => children_results.iter().sum(),
+    })
+}
+
+fn build_file_tree(input: &[Input]) -> FileTree {
+    let mut file_tree_builder = FileTree::builder();
+
+    for input_line in input {
+        match input_line {
+            Input::Command(cmd) => match cmd {
+                Command::Cd(arg) => match arg {
+                    CdArg::Parent => file_tree_builder.cd_parent(),
+                    CdArg::Root => file_tree_builder.cd_root(),
+                    CdArg::Dir(dir) => file_tree_builder.cd(dir),
+                },
+                Command::Ls => {}
+            },
+            Input::LsOutput(output) => match output {
+                LsOutput::Dir(name) => file_tree_builder.mkdir(name),
+                LsOutput::File(name, size) => file_tree_builder.touch(name, *size),
+            },
+        }
+    }
+    file_tree_builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day7;
+    use advent_util::examples::assert_fixtures;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day7, env!("CARGO_MANIFEST_DIR"));
+    }
+}
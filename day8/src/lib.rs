@@ -0,0 +1,296 @@
+use advent_util::grid::{Grid, Position};
+use advent_util::parse::ParseError;
+use advent_util::solution::Solution;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    /// Heights of the trees.
+    type Input = Grid<i8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(Grid::parse(input, |c| {
+            c.to_digit(10)
+                .map(|height| height as i8)
+                .ok_or_else(|| ParseError::new("Unexpected tree height", &c.to_string()))
+        })?)
+    }
+
+    fn part_one(&self, trees: &Self::Input) -> Self::PartOne {
+        let max_trees_before = calculate_max_trees_before(trees);
+        trees
+            .iter()
+            .filter(|(position, tree)| max_trees_before[*position].is_tree_visible(**tree))
+            .count()
+    }
+
+    fn part_two(&self, trees: &Self::Input) -> Self::PartTwo {
+        let max_trees_before = calculate_max_trees_before(trees);
+        trees
+            .positions()
+            .map(|tree| calculate_score(trees, &max_trees_before, tree))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn calculate_score(
+    trees: &Grid<i8>,
+    max_trees_before: &Grid<MaxTreesBefore>,
+    (i, j): Position,
+) -> usize {
+    let (width, height) = (trees.width(), trees.height());
+    let left: usize = {
+        if max_trees_before[(i, j)].left() < trees[(i, j)] {
+            j
+        } else {
+            (max_trees_before[(i, j)].left_pos()..j)
+                .rev()
+                .take_while(|neighbour| trees[(i, j)] > trees[(i, *neighbour)])
+                .count()
+                + 1
+        }
+    };
+    let top: usize = {
+        if max_trees_before[(i, j)].top() < trees[(i, j)] {
+            i
+        } else {
+            (max_trees_before[(i, j)].top_pos()..i)
+                .rev()
+                .take_while(|neighbour| trees[(i, j)] > trees[(*neighbour, j)])
+                .count()
+                + 1
+        }
+    };
+    let right: usize = {
+        if max_trees_before[(i, j)].right() < trees[(i, j)] {
+            width - 1 - j
+        } else {
+            (j + 1..max_trees_before[(i, j)].right_pos())
+                .take_while(|neighbour| trees[(i, j)] > trees[(i, *neighbour)])
+                .count()
+                + 1
+        }
+    };
+    let bottom: usize = {
+        if max_trees_before[(i, j)].bottom() < trees[(i, j)] {
+            height - 1 - i
+        } else {
+            (i + 1..max_trees_before[(i, j)].bottom_pos())
+                .take_while(|neighbour| trees[(i, j)] > trees[(*neighbour, j)])
+                .count()
+                + 1
+        }
+    };
+    left * right * top * bottom
+}
+
+/// The highest trees between every tree and each border with the closest positions of them.
+fn calculate_max_trees_before(trees: &Grid<i8>) -> Grid<MaxTreesBefore> {
+    let (width, height) = (trees.width(), trees.height());
+    let mut max_length_before = Grid::filled(MaxTreesBefore::new(), height, width);
+    for i in 0..height {
+        for j in 0..width {
+            if i > 0 {
+                let before = max_length_before[(i - 1, j)].clone();
+                if before.top() <= trees[(i - 1, j)] {
+                    max_length_before[(i, j)].set_top(trees[(i - 1, j)], i - 1);
+                } else {
+                    max_length_before[(i, j)].set_top(before.top(), before.top_pos());
+                }
+            }
+
+            if j > 0 {
+                let before = max_length_before[(i, j - 1)].clone();
+                if before.left() <= trees[(i, j - 1)] {
+                    max_length_before[(i, j)].set_left(trees[(i, j - 1)], j - 1);
+                } else {
+                    max_length_before[(i, j)].set_left(before.left(), before.left_pos());
+                }
+            }
+        }
+    }
+    for i in (0..height).rev() {
+        for j in (0..width).rev() {
+            if i < height - 1 {
+                let before = max_length_before[(i + 1, j)].clone();
+                if before.bottom() <= trees[(i + 1, j)] {
+                    max_length_before[(i, j)].set_bottom(trees[(i + 1, j)], i + 1);
+                } else {
+                    max_length_before[(i, j)].set_bottom(before.bottom(), before.bottom_pos());
+                }
+            }
+            if j < width - 1 {
+                let before = max_length_before[(i, j + 1)].clone();
+                if before.right() <= trees[(i, j + 1)] {
+                    max_length_before[(i, j)].set_right(trees[(i, j + 1)], j + 1);
+                } else {
+                    max_length_before[(i, j)].set_right(before.right(), before.right_pos());
+                }
+            }
+        }
+    }
+    max_length_before
+}
+
+#[derive(Debug, Clone)]
+struct MaxTreesBefore {
+    max_values: [i8; 4],
+    max_positions: [usize; 4],
+}
+
+impl MaxTreesBefore {
+    fn new() -> Self {
+        Self {
+            max_values: [-1; 4],
+            max_positions: [0; 4],
+        }
+    }
+
+    fn top(&self) -> i8 {
+        self.max_values[0]
+    }
+
+    fn left(&self) -> i8 {
+        self.max_values[1]
+    }
+
+    fn right(&self) -> i8 {
+        self.max_values[2]
+    }
+
+    fn bottom(&self) -> i8 {
+        self.max_values[3]
+    }
+
+    fn set_top(&mut self, value: i8, position: usize) {
+        self.max_values[0] = value;
+        self.max_positions[0] = position;
+    }
+
+    fn set_left(&mut self, value: i8, position: usize) {
+        self.max_values[1] = value;
+        self.max_positions[1] = position;
+    }
+
+    fn set_right(&mut self, value: i8, position: usize) {
+        self.max_values[2] = value;
+        self.max_positions[2] = position;
+    }
+
+    fn set_bottom(&mut self, value: i8, position: usize) {
+        self.max_values[3] = value;
+        self.max_positions[3] = position;
+    }
+
+    fn top_pos(&self) -> usize {
+        self.max_positions[0]
+    }
+
+    fn left_pos(&self) -> usize {
+        self.max_positions[1]
+    }
+
+    fn right_pos(&self) -> usize {
+        self.max_positions[2]
+    }
+
+    fn bottom_pos(&self) -> usize {
+        self.max_positions[3]
+    }
+
+    fn is_tree_visible(&self, tree: i8) -> bool {
+        self.max_values
+            .iter()
+            .any(|max_tree_before| *max_tree_before < tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{calculate_max_trees_before, calculate_score, Day8};
+    use advent_util::examples::assert_fixtures;
+    use advent_util::solution::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day8, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    /// Trees from the given one to the border in every direction, the closest first.
+    fn brute_force_lines(heights: &[Vec<i8>], row: usize, column: usize) -> [Vec<i8>; 4] {
+        let up = (0..row).rev().map(|r| heights[r][column]).collect();
+        let down = (row + 1..heights.len())
+            .map(|r| heights[r][column])
+            .collect();
+        let left = heights[row][..column].iter().rev().copied().collect();
+        let right = heights[row][column + 1..].to_vec();
+        [up, down, left, right]
+    }
+
+    fn brute_force_visible(heights: &[Vec<i8>], row: usize, column: usize) -> bool {
+        let tree = heights[row][column];
+        brute_force_lines(heights, row, column)
+            .iter()
+            .any(|line| line.iter().all(|&other| other < tree))
+    }
+
+    fn brute_force_score(heights: &[Vec<i8>], row: usize, column: usize) -> usize {
+        let tree = heights[row][column];
+        let mut score = 1;
+        for line in brute_force_lines(heights, row, column) {
+            let mut distance = 0;
+            for other in line {
+                distance += 1;
+                if other >= tree {
+                    break;
+                }
+            }
+            score *= distance;
+        }
+        score
+    }
+
+    fn forest() -> impl Strategy<Value = Vec<Vec<i8>>> {
+        (1..10usize, 1..10usize).prop_flat_map(|(rows, columns)| {
+            prop::collection::vec(prop::collection::vec(0..=9i8, columns), rows)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn trees_match_brute_force(heights in forest()) {
+            let input = heights
+                .iter()
+                .map(|row| row.iter().map(|height| height.to_string()).collect::<String>() + "\n")
+                .collect::<String>();
+            let trees = Day8.parse(&input).unwrap();
+            let max_trees_before = calculate_max_trees_before(&trees);
+            for (row, column) in trees.positions() {
+                let visible = brute_force_visible(&heights, row, column);
+                let score = brute_force_score(&heights, row, column);
+                prop_assert_eq!(
+                    max_trees_before[(row, column)].is_tree_visible(trees[(row, column)]),
+                    visible
+                );
+                prop_assert_eq!(calculate_score(&trees, &max_trees_before, (row, column)), score);
+            }
+
+            prop_assert_eq!(
+                Day8.part_one(&trees),
+                trees.positions().filter(|&(row, column)| brute_force_visible(&heights, row, column)).count()
+            );
+            prop_assert_eq!(
+                Day8.part_two(&trees),
+                trees.positions().map(|(row, column)| brute_force_score(&heights, row, column)).max().unwrap()
+            );
+        }
+    }
+}
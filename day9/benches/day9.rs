@@ -0,0 +1,21 @@
+use advent_util::bench::bench_input;
+use advent_util::solution::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day9::Day9;
+use std::hint::black_box;
+
+fn bench(c: &mut Criterion) {
+    let input = bench_input(9, env!("CARGO_MANIFEST_DIR"));
+    let parsed = Day9.parse(&input).unwrap();
+
+    c.bench_function("day9 parse", |b| b.iter(|| Day9.parse(black_box(&input))));
+    c.bench_function("day9 part 1", |b| {
+        b.iter(|| Day9.part_one(black_box(&parsed)))
+    });
+    c.bench_function("day9 part 2", |b| {
+        b.iter(|| Day9.part_two(black_box(&parsed)))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use crate::rope::{Move, RopeMover};
+use advent_util::parse::lines;
+use advent_util::point::Point2;
+use advent_util::solution::Solution;
+use std::collections::HashSet;
+
+pub mod rope;
+pub mod simulation;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        Ok(lines(input, str::parse::<Move>)?)
+    }
+
+    fn part_one(&self, moves: &Self::Input) -> Self::PartOne {
+        let mut rope_mover = RopeMover::new(2);
+        move_rope(moves, &mut rope_mover).len()
+    }
+
+    fn part_two(&self, moves: &Self::Input) -> Self::PartTwo {
+        let mut rope_mover = RopeMover::new(10);
+        move_rope(moves, &mut rope_mover).len()
+    }
+}
+
+fn move_rope(moves: &[Move], rope_mover: &mut RopeMover) -> HashSet<Point2> {
+    for mv in moves {
+        rope_mover.move_head(*mv);
+    }
+
+    let mut different_positions = HashSet::new();
+    for pos in rope_mover.tail_trail() {
+        different_positions.insert(*pos);
+    }
+    different_positions
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day9;
+    use advent_util::examples::assert_fixtures;
+    use advent_util::solution::Solution;
+
+    #[test]
+    fn examples() {
+        assert_fixtures(&Day9, env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn only_straight_moves() {
+        assert_eq!(
+            Day9.parse("R 4\nNE 2").err(),
+            Some("line 2, column 1: Expected U, D, L or R: 'NE'".to_string())
+        );
+        assert!(Day9.parse("^ 1").is_err());
+    }
+}
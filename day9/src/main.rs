@@ -1,39 +1,59 @@
-use crate::rope::{Move, Pos, RopeMover};
-use advent_util::read_input;
-use std::collections::HashSet;
+use advent_util::solution::{run_with_args, MainArgs, Solution};
+use advent_util::tui;
+use advent_util::viz::{Animation, Palette, VizTarget};
+use day9::rope::RopeMover;
+use day9::simulation::RopeSimulation;
+use day9::Day9;
 
-mod rope;
+/// Longer movements are sampled to keep the animation small.
+const MAX_FRAMES: usize = 200;
+const KNOTS: usize = 10;
 
 fn main() {
-    let moves = read_input(9)
-        .unwrap()
-        .lines()
-        .map(|line| line.parse::<Move>().unwrap())
-        .collect::<Vec<_>>();
-
-    let mut rope_mover = RopeMover::new(2);
-    let different_positions = move_rope(&moves, &mut rope_mover);
-    println!(
-        "Amount of positions of tail for 2-rope: {}",
-        different_positions.len()
-    );
-
-    let mut rope_mover = RopeMover::new(10);
-    let different_positions = move_rope(&moves, &mut rope_mover);
-    println!(
-        "Amount of positions of tail for 10-rope: {}",
-        different_positions.len()
-    );
+    if let Some(args) = tui::from_env(9) {
+        explore(&args);
+        return;
+    }
+    let (args, target) = VizTarget::from_env(9);
+    match target {
+        Some(target) => visualize(&args, &target),
+        None => run_with_args(&Day9, &args),
+    }
 }
 
-fn move_rope(moves: &[Move], rope_mover: &mut RopeMover) -> HashSet<Pos> {
-    for mv in moves {
-        rope_mover.move_head(*mv);
-    }
+fn visualize(args: &MainArgs, target: &VizTarget) {
+    let input = args.input.read().unwrap_or_else(|err| panic!("{}", err));
+    let moves = Day9.parse(&input).unwrap_or_else(|err| panic!("{}", err));
 
-    let mut different_positions = HashSet::new();
-    for pos in rope_mover.tail_trail() {
-        different_positions.insert(*pos);
+    // all frames show the same area, so the rope does not jump around
+    let bounds = RopeMover::movement_bounds(&moves, KNOTS);
+    let steps = moves.iter().map(|mv| mv.steps()).sum::<usize>();
+    let every = steps.div_ceil(MAX_FRAMES).max(1);
+    let palette = Palette::default()
+        .with('#', [90, 110, 160])
+        .with('s', [80, 200, 80])
+        .with('H', [230, 60, 60]);
+    let mut animation = Animation::new(palette).scale(2);
+    let mut rope_mover = RopeMover::new(KNOTS);
+    let mut step = 0usize;
+    for mv in &moves {
+        rope_mover.move_head_observed(*mv, |mover| {
+            if step.is_multiple_of(every) {
+                animation.push_text(&mover.render(bounds));
+            }
+            step += 1;
+        });
     }
-    different_positions
+    animation.push_text(&rope_mover.render(bounds));
+
+    animation
+        .write(target)
+        .unwrap_or_else(|err| panic!("{}", err));
+}
+
+fn explore(args: &MainArgs) {
+    let input = args.input.read().unwrap_or_else(|err| panic!("{}", err));
+    let moves = Day9.parse(&input).unwrap_or_else(|err| panic!("{}", err));
+    tui::explore("Rope", RopeSimulation::new(&moves, KNOTS))
+        .unwrap_or_else(|err| panic!("Terminal error: {}", err));
 }
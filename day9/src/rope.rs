@@ -1,140 +1,155 @@
-use advent_util::slices::foreach_windows;
+use advent_util::grid::Grid;
+use advent_util::parse::{number, pair, ParseError};
+use advent_util::point::{Direction, Point2};
+use advent_util::windows::windows_mut;
 use std::str::FromStr;
 
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
-pub struct Pos {
-    x: i32,
-    y: i32,
-}
-
-impl Pos {
-    fn move_towards(&mut self, direction: Direction) {
-        match direction {
-            Direction::Right => {
-                self.x += 1;
-            }
-            Direction::Left => {
-                self.x -= 1;
-            }
-            Direction::Up => {
-                self.y += 1;
-            }
-            Direction::Down => {
-                self.y -= 1;
-            }
-        }
-    }
-}
-
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Rope {
-    knots: Vec<Pos>,
+    knots: Vec<Point2>,
 }
 
 impl Rope {
     pub fn new(size: usize) -> Self {
         assert!(size > 0);
         Self {
-            knots: vec![Pos::default(); size],
+            knots: vec![Point2::ZERO; size],
         }
     }
 
-    fn head_mut(&mut self) -> &mut Pos {
+    fn head_mut(&mut self) -> &mut Point2 {
         &mut self.knots[0]
     }
 
-    fn knots_mut(&mut self) -> &mut [Pos] {
+    fn knots_mut(&mut self) -> &mut [Point2] {
         &mut self.knots
     }
 
-    fn tail(&self) -> Pos {
+    fn tail(&self) -> Point2 {
         *self.knots.last().unwrap()
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub enum Direction {
-    Right,
-    Left,
-    Up,
-    Down,
-}
-
 #[derive(Debug, Copy, Clone)]
 pub struct Move {
     direction: Direction,
     steps: usize,
 }
 
+impl Move {
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
 impl FromStr for Move {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.strip_prefix("R ")
-            .map(|rest| (Direction::Right, rest))
-            .or_else(|| s.strip_prefix("L ").map(|rest| (Direction::Left, rest)))
-            .or_else(|| s.strip_prefix("U ").map(|rest| (Direction::Up, rest)))
-            .or_else(|| s.strip_prefix("D ").map(|rest| (Direction::Down, rest)))
-            .ok_or_else(|| format!("Unexpected move command: {}", s))
-            .and_then(|(direction, rest)| {
-                rest.parse::<usize>()
-                    .map_err(|msg| format!("Cannot parse steps: {}", msg))
-                    .map(|steps| Self { direction, steps })
-            })
+        let (direction, steps) = pair(s, " ", direction, number)?;
+        Ok(Self { direction, steps })
     }
 }
 
-#[derive(Debug)]
+/// Only `U`, `D`, `L` and `R`, the head never moves diagonally.
+fn direction(text: &str) -> Result<Direction, ParseError> {
+    match text {
+        "U" => Ok(Direction::Up),
+        "D" => Ok(Direction::Down),
+        "L" => Ok(Direction::Left),
+        "R" => Ok(Direction::Right),
+        _ => Err(ParseError::new("Expected U, D, L or R", text)),
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct RopeMover {
     rope: Rope,
-    tail_trail: Vec<Pos>,
+    tail_trail: Vec<Point2>,
 }
 
 impl RopeMover {
     pub fn new(knots_amount: usize) -> Self {
         Self {
             rope: Rope::new(knots_amount),
-            tail_trail: vec![Pos::default()], // at start tail already was in (0, 0)
+            tail_trail: vec![Point2::ZERO], // at start tail already was in (0, 0)
         }
     }
 
     pub fn move_head(&mut self, move_info: Move) {
+        self.move_head_observed(move_info, |_| {});
+    }
+
+    /// Moves the head step by step and calls `observe` after every step.
+    pub fn move_head_observed(&mut self, move_info: Move, mut observe: impl FnMut(&Self)) {
         for _ in 0..move_info.steps {
             self.move_head_once(move_info.direction);
+            observe(self);
         }
     }
 
-    fn move_head_once(&mut self, direction: Direction) {
-        self.rope.head_mut().move_towards(direction);
+    pub fn move_head_once(&mut self, direction: Direction) {
+        *self.rope.head_mut() += direction.delta();
 
-        foreach_windows::<_, 2, _>(self.rope.knots_mut(), |[prev, current]| {
-            Self::move_according_knot_previous(*prev, current)
-        });
+        windows_mut::<_, 2>(self.rope.knots_mut())
+            .for_each(|[prev, current]| Self::move_according_knot_previous(*prev, current));
 
         self.tail_trail.push(self.rope.tail());
     }
 
-    fn move_according_knot_previous(prev: Pos, current: &mut Pos) {
-        let x_diff = prev.x - current.x;
-        let y_diff = prev.y - current.y;
-        if x_diff.abs() <= 1 && y_diff.abs() <= 1 {
-            return;
+    fn move_according_knot_previous(prev: Point2, current: &mut Point2) {
+        if prev.chebyshev(*current) > 1 {
+            *current += (prev - *current).signum();
         }
+    }
 
-        if x_diff.abs() > 1 {
-            current.x += x_diff.signum();
-            if y_diff != 0 {
-                current.y += y_diff.signum();
-            }
-        } else if y_diff.abs() > 1 {
-            current.y += y_diff.signum();
-            if x_diff != 0 {
-                current.x += x_diff.signum();
-            }
+    pub fn tail_trail(&self) -> &[Point2] {
+        self.tail_trail.as_slice()
+    }
+
+    pub fn knots(&self) -> &[Point2] {
+        &self.rope.knots
+    }
+
+    /// Inclusive corners of the area visited by all knots while following the moves.
+    pub fn movement_bounds(moves: &[Move], knots_amount: usize) -> (Point2, Point2) {
+        let mut bounds = (Point2::ZERO, Point2::ZERO);
+        let mut rope_mover = Self::new(knots_amount);
+        for mv in moves {
+            rope_mover.move_head_observed(*mv, |mover| {
+                let corners = [bounds.0, bounds.1];
+                bounds = Point2::bounds(mover.knots().iter().copied().chain(corners))
+                    .expect("rope has knots");
+            });
         }
+        bounds
     }
 
-    pub fn tail_trail(&self) -> &[Pos] {
-        self.tail_trail.as_slice()
+    /// The area between the inclusive corners with the start as `s`, the trail of the tail
+    /// as `#`, the head as `H` and other knots as their numbers.
+    pub fn render(&self, (min, max): (Point2, Point2)) -> String {
+        let size = max - min;
+        let mut area = Grid::filled('.', size.y as usize + 1, size.x as usize + 1);
+        let mut mark = |point: Point2, mark: char| {
+            if let Some(cell) = (point - min).to_position().and_then(|p| area.get_mut(p)) {
+                *cell = mark;
+            }
+        };
+        for point in &self.tail_trail {
+            mark(*point, '#');
+        }
+        mark(Point2::ZERO, 's');
+        for (i, knot) in self.knots().iter().enumerate().rev() {
+            let label = match i {
+                0 => 'H',
+                i => char::from_digit(i as u32, 10).unwrap_or('k'),
+            };
+            mark(*knot, label);
+        }
+        area.render(|cell| *cell)
     }
 }
@@ -0,0 +1,95 @@
+use crate::rope::{Move, RopeMover};
+use advent_util::point::Point2;
+use advent_util::tui::Simulation;
+use std::collections::HashSet;
+
+/// Rope following the moves a step of the head at a time.
+#[derive(Debug, Clone)]
+pub struct RopeSimulation {
+    rope_mover: RopeMover,
+    moves: Vec<Move>,
+    /// Index of the current move and the number of its steps already done.
+    position: (usize, usize),
+    /// Area of the whole movement, so the map does not jump around.
+    bounds: (Point2, Point2),
+}
+
+impl RopeSimulation {
+    pub fn new(moves: &[Move], knots_amount: usize) -> Self {
+        Self {
+            rope_mover: RopeMover::new(knots_amount),
+            moves: moves.to_vec(),
+            position: (0, 0),
+            bounds: RopeMover::movement_bounds(moves, knots_amount),
+        }
+    }
+}
+
+impl Simulation for RopeSimulation {
+    fn step(&mut self) -> bool {
+        let (mut index, mut done) = self.position;
+        while let Some(mv) = self.moves.get(index) {
+            if done < mv.steps() {
+                self.rope_mover.move_head_once(mv.direction());
+                self.position = (index, done + 1);
+                return true;
+            }
+            index += 1;
+            done = 0;
+        }
+        false
+    }
+
+    fn render(&self) -> String {
+        self.rope_mover.render(self.bounds)
+    }
+
+    fn status(&self) -> Vec<(String, String)> {
+        let (index, done) = self.position;
+        let current_move = self.moves.get(index).map_or("-".to_string(), |mv| {
+            format!(
+                "{:?} {} ({}/{})",
+                mv.direction(),
+                mv.steps(),
+                done,
+                mv.steps()
+            )
+        });
+        let knots = self.rope_mover.knots();
+        let visited = self
+            .rope_mover
+            .tail_trail()
+            .iter()
+            .collect::<HashSet<_>>()
+            .len();
+        vec![
+            (
+                "Move".to_string(),
+                format!("{} of {}", index + 1, self.moves.len()),
+            ),
+            ("Current".to_string(), current_move),
+            ("Head".to_string(), knots[0].to_string()),
+            ("Tail".to_string(), knots[knots.len() - 1].to_string()),
+            ("Visited by tail".to_string(), visited.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rope::Move;
+    use crate::simulation::RopeSimulation;
+    use advent_util::tui::{Explorer, Simulation};
+
+    #[test]
+    fn step_through_moves() {
+        let moves = ["R 2", "U 1"].map(|mv| mv.parse::<Move>().unwrap());
+        let mut explorer = Explorer::new(RopeSimulation::new(&moves, 2));
+        explorer.jump_to_end();
+        assert_eq!(explorer.last_step(), Some(3));
+        assert_eq!(explorer.current().render(), "..H\ns1.");
+
+        explorer.back(2);
+        assert_eq!(explorer.current().render(), "...\n1H.");
+    }
+}
@@ -1,2 +0,0 @@
-const day = request.url.getRaw().split("/")[3];
-request.variables.set("dayN", `day${day}`);
\ No newline at end of file